@@ -1,12 +1,15 @@
 mod interact_cli;
 mod interact_config;
+mod interact_events;
 mod interact_state;
 mod ping_pong_proxy;
 
 use clap::Parser;
 pub use interact_config::Config;
+pub use interact_events::PingPongEvent;
 use interact_state::State;
 use multiversx_sc_snippets::imports::*;
+pub use ping_pong_proxy::Role;
 
 const PING_PONG_CODE: MxscPath = MxscPath::new("output/ping-pong.mxsc.json");
 pub const EGLD: &str = "EGLD";
@@ -26,12 +29,21 @@ pub async fn ping_pong_cli() {
                     args.ping_amount.clone(),
                     args.duration_in_seconds,
                     args.token_id.clone(),
+                    args.activation_window(),
+                    args.max_ping_amount.clone(),
+                    args.treasury(),
                 )
                 .await;
         }
         Some(interact_cli::InteractCliCommand::Upgrade(args)) => {
             interact
-                .upgrade(args.ping_amount.clone(), args.duration_in_seconds)
+                .upgrade(
+                    args.ping_amount.clone(),
+                    args.duration_in_seconds,
+                    args.activation_window(),
+                    args.max_ping_amount.clone(),
+                    args.treasury(),
+                )
                 .await;
         }
         Some(interact_cli::InteractCliCommand::Ping(args)) => {
@@ -41,15 +53,331 @@ pub async fn ping_pong_cli() {
                     args.nonce,
                     args.amount,
                     &interact.alice_wallet_address.clone(),
+                    args.tier_id,
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::PingFor(args)) => {
+            let beneficiary = Bech32Address::from_bech32_string(args.beneficiary.clone());
+            interact
+                .ping_for(
+                    args.token.clone(),
+                    args.nonce,
+                    args.amount,
+                    &interact.alice_wallet_address.clone(),
+                    beneficiary,
+                    args.tier_id,
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::Pong(args)) => {
+            interact
+                .pong(
+                    &interact.alice_wallet_address.clone(),
+                    args.deposit_id,
+                    args.receipt_nonce.as_slice(),
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::PongTo(args)) => {
+            let destination = Bech32Address::from_bech32_string(args.destination.clone());
+            interact
+                .pong_to(
+                    &interact.alice_wallet_address.clone(),
+                    destination,
+                    args.deposit_id,
+                    args.receipt_nonce.as_slice(),
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::PongPartial(args)) => {
+            interact
+                .pong_partial(
+                    &interact.alice_wallet_address.clone(),
+                    args.amount.clone(),
+                    args.deposit_id,
+                    args.receipt_nonce.as_slice(),
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::ExtendLock(args)) => {
+            interact
+                .extend_lock(
+                    &interact.alice_wallet_address.clone(),
+                    args.extra_seconds,
+                    args.deposit_id,
+                    args.receipt_nonce.as_slice(),
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::SetAutoRenew(args)) => {
+            interact
+                .set_auto_renew(
+                    &interact.alice_wallet_address.clone(),
+                    args.enabled,
+                    args.deposit_id,
+                    args.receipt_nonce.as_slice(),
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::EarlyPong(args)) => {
+            interact
+                .early_pong(
+                    &interact.alice_wallet_address.clone(),
+                    args.deposit_id,
+                    args.receipt_nonce.as_slice(),
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::PongMatured(args)) => {
+            interact
+                .pong_matured(&interact.alice_wallet_address.clone(), args.max_count, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::EmergencyRefund(args)) => {
+            interact
+                .emergency_refund(&interact.alice_wallet_address.clone(), args.max_count, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::PongAll(args)) => {
+            interact
+                .pong_all(
+                    &interact.alice_wallet_address.clone(),
+                    &args.receipt_nonces,
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::IssueReceiptToken(args)) => {
+            interact
+                .issue_receipt_token(
+                    args.issue_cost.clone(),
+                    &args.token_display_name,
+                    &args.token_ticker,
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::SetReceiptTransferable(args)) => {
+            interact
+                .set_receipt_transferable(args.transferable, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::GetReceiptTokenId) => {
+            interact.receipt_token_id().await;
+        }
+        Some(interact_cli::InteractCliCommand::IsReceiptTransferable) => {
+            interact.is_receipt_transferable().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetDepositReceiptNonce(args)) => {
+            interact.deposit_receipt_nonce(args.deposit_id).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetReceiptDepositId(args)) => {
+            interact.receipt_deposit_id(args.receipt_nonce).await;
+        }
+        Some(interact_cli::InteractCliCommand::SetMultipleDeposits(args)) => {
+            interact.set_multiple_deposits(args.enabled, None).await;
+        }
+        Some(interact_cli::InteractCliCommand::SetPingForPonger(args)) => {
+            let ponger = match args.ponger {
+                interact_cli::Ponger::Beneficiary => ping_pong_proxy::PingForPonger::Beneficiary,
+                interact_cli::Ponger::Funder => ping_pong_proxy::PingForPonger::Funder,
+            };
+            interact.set_ping_for_ponger(ponger, None).await;
+        }
+        Some(interact_cli::InteractCliCommand::AddAcceptedToken(args)) => {
+            interact
+                .add_accepted_token(
+                    args.token_id.clone(),
+                    args.ping_amount.clone(),
+                    args.max_ping_amount.clone(),
+                    None,
+                )
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::RemoveAcceptedToken(args)) => {
+            interact
+                .remove_accepted_token(args.token_id.clone(), None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::SetAcceptedTokenNonce(args)) => {
+            interact
+                .set_accepted_token_nonce(args.token_id.clone(), args.nonce, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::Pause) => {
+            interact.pause(None).await;
+        }
+        Some(interact_cli::InteractCliCommand::Unpause) => {
+            interact.unpause(None).await;
+        }
+        Some(interact_cli::InteractCliCommand::PausePong) => {
+            interact.pause_pong(None).await;
+        }
+        Some(interact_cli::InteractCliCommand::UnpausePong) => {
+            interact.unpause_pong(None).await;
+        }
+        Some(interact_cli::InteractCliCommand::IsPaused) => {
+            interact.is_paused().await;
+        }
+        Some(interact_cli::InteractCliCommand::IsPongPaused) => {
+            interact.is_pong_paused().await;
+        }
+        Some(interact_cli::InteractCliCommand::IsMultipleDepositsEnabled) => {
+            interact.is_multiple_deposits_enabled().await;
+        }
+        Some(interact_cli::InteractCliCommand::SetPartialPongRemainder(args)) => {
+            let remainder = match args.remainder {
+                interact_cli::Remainder::KeepUnlocked => {
+                    ping_pong_proxy::PartialPongRemainder::KeepUnlocked
+                }
+                interact_cli::Remainder::Relock => ping_pong_proxy::PartialPongRemainder::Relock,
+            };
+            interact.set_partial_pong_remainder(remainder, None).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetPartialPongRemainder) => {
+            interact.partial_pong_remainder().await;
+        }
+        Some(interact_cli::InteractCliCommand::SetAutoRenewGracePeriod(args)) => {
+            interact
+                .set_auto_renew_grace_period(args.grace_period, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::SetConfigTimelock(args)) => {
+            interact
+                .set_config_timelock(args.delay_in_seconds, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::ProposeConfig(args)) => {
+            interact
+                .propose_config(args.ping_amount.clone(), args.duration_in_seconds, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::ExecuteConfigProposal(args)) => {
+            interact
+                .execute_config_proposal(args.proposal_id, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::CancelConfigProposal(args)) => {
+            interact
+                .cancel_config_proposal(args.proposal_id, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::GetConfigTimelock) => {
+            interact.config_timelock().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetPendingConfigTimelock) => {
+            interact.pending_config_timelock().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetPendingConfigProposals) => {
+            interact.pending_config_proposals().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetAutoRenewGracePeriod) => {
+            interact.auto_renew_grace_period().await;
+        }
+        Some(interact_cli::InteractCliCommand::IsAutoRenewEnabled(args)) => {
+            interact.is_auto_renew_enabled(args.deposit_id).await;
+        }
+        Some(interact_cli::InteractCliCommand::SetEarlyPongPenalty(args)) => {
+            let penalty_type = match args.penalty_type {
+                interact_cli::PenaltyType::Flat => ping_pong_proxy::PenaltyType::Flat,
+                interact_cli::PenaltyType::Linear => ping_pong_proxy::PenaltyType::Linear,
+            };
+            interact
+                .set_early_pong_penalty(penalty_type, args.penalty_rate, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::GetEarlyPongPenaltyConfig) => {
+            interact.early_pong_penalty_config().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetTreasury) => {
+            interact.treasury().await;
+        }
+        Some(interact_cli::InteractCliCommand::SetPingFee(args)) => {
+            let fee_type = match args.fee_type {
+                interact_cli::PingFeeType::Rate => ping_pong_proxy::PingFeeType::Rate,
+                interact_cli::PingFeeType::Flat => ping_pong_proxy::PingFeeType::Flat,
+            };
+            interact
+                .set_ping_fee(fee_type, args.fee.clone(), None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::SetFeeCollector(args)) => {
+            let fee_collector = Bech32Address::from_bech32_string(args.address.clone());
+            interact.set_fee_collector(fee_collector, None).await;
+        }
+        Some(interact_cli::InteractCliCommand::ClaimFees) => {
+            interact.claim_fees(None).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetPingFeeConfig) => {
+            interact.ping_fee_config().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetFeeCollector) => {
+            interact.fee_collector().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetAccumulatedFees) => {
+            interact.accumulated_fees().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetEarlyPongPenalty(args)) => {
+            let address = Bech32Address::from_bech32_string(args.address.clone());
+            interact.early_pong_penalty(address).await;
+        }
+        Some(interact_cli::InteractCliCommand::FundRewards(args)) => {
+            interact
+                .fund_rewards(args.token.clone(), args.nonce, args.amount, None)
+                .await;
+        }
+        Some(interact_cli::InteractCliCommand::SetLockTier(args)) => {
+            interact
+                .set_lock_tier(
+                    args.tier_id,
+                    args.duration_in_seconds,
+                    args.reward_multiplier,
                     None,
                 )
                 .await;
         }
-        Some(interact_cli::InteractCliCommand::Pong) => {
+        Some(interact_cli::InteractCliCommand::RemoveLockTier(args)) => {
+            interact.remove_lock_tier(args.tier_id, None).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetLockTiers) => {
+            interact.lock_tiers().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetDepositLockTier(args)) => {
+            interact.deposit_lock_tier(args.deposit_id).await;
+        }
+        Some(interact_cli::InteractCliCommand::SetRewardConfig(args)) => {
+            let empty_pool_policy = match args.empty_pool_policy {
+                interact_cli::EmptyPoolPolicy::Fail => ping_pong_proxy::EmptyPoolPolicy::Fail,
+                interact_cli::EmptyPoolPolicy::PrincipalOnly => {
+                    ping_pong_proxy::EmptyPoolPolicy::PrincipalOnly
+                }
+            };
             interact
-                .pong(&interact.alice_wallet_address.clone(), None)
+                .set_reward_config(args.reward_rate, empty_pool_policy, None)
                 .await;
         }
+        Some(interact_cli::InteractCliCommand::GetRewardConfig) => {
+            interact.reward_config().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetRewardPool(args)) => {
+            interact.reward_pool(args.token_id.clone()).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetPendingReward(args)) => {
+            let address = Bech32Address::from_bech32_string(args.address.clone());
+            interact.pending_reward(address).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetPingForPonger) => {
+            interact.ping_for_ponger().await;
+        }
         Some(interact_cli::InteractCliCommand::DidUserPing(args)) => {
             let address = Bech32Address::from_bech32_string(args.address.clone());
             interact.did_user_ping(address).await;
@@ -68,6 +396,18 @@ pub async fn ping_pong_cli() {
         Some(interact_cli::InteractCliCommand::GetPingAmount) => {
             interact.ping_amount().await;
         }
+        Some(interact_cli::InteractCliCommand::GetMaxPingAmount) => {
+            interact.max_ping_amount().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetAcceptedTokens) => {
+            interact.accepted_tokens().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetAcceptedTokenNonce(args)) => {
+            interact.accepted_token_nonce(args.token_id.clone()).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetDepositTokenNonce(args)) => {
+            interact.deposit_token_nonce(args.deposit_id).await;
+        }
         Some(interact_cli::InteractCliCommand::GetDurationTimestamp) => {
             interact.duration_in_seconds().await;
         }
@@ -75,6 +415,65 @@ pub async fn ping_pong_cli() {
             let address = Bech32Address::from_bech32_string(args.address.clone());
             interact.user_ping_timestamp(address).await;
         }
+        Some(interact_cli::InteractCliCommand::GetActivationWindow) => {
+            interact.activation_window().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetActiveUserCount) => {
+            interact.active_user_count().await;
+        }
+        Some(interact_cli::InteractCliCommand::GrantRole(args)) => {
+            let address = Bech32Address::from_bech32_string(args.address.clone());
+            interact.grant_role(args.role.into(), address, None).await;
+        }
+        Some(interact_cli::InteractCliCommand::RevokeRole(args)) => {
+            let address = Bech32Address::from_bech32_string(args.address.clone());
+            interact.revoke_role(args.role.into(), address, None).await;
+        }
+        Some(interact_cli::InteractCliCommand::HasRole(args)) => {
+            let address = Bech32Address::from_bech32_string(args.address.clone());
+            interact.has_role(args.role.into(), address).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetRoleMembers(args)) => {
+            interact.role_members(args.role.into()).await;
+        }
+        Some(interact_cli::InteractCliCommand::EnterEmergency) => {
+            interact.enter_emergency(None).await;
+        }
+        Some(interact_cli::InteractCliCommand::IsEmergency) => {
+            interact.is_emergency().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetEmergencyTimestamp) => {
+            interact.emergency_timestamp().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetEmergencyRefundCursor) => {
+            interact.emergency_refund_cursor().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetPongMaturedCursor) => {
+            interact.pong_matured_cursor().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetActiveUsers(args)) => {
+            interact.active_users(args.from, args.size).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetStats) => {
+            interact.stats().await;
+        }
+        Some(interact_cli::InteractCliCommand::GetUserDeposit(args)) => {
+            let address = Bech32Address::from_bech32_string(args.address.clone());
+            interact.user_deposit(address).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetUserDeposits(args)) => {
+            let address = Bech32Address::from_bech32_string(args.address.clone());
+            interact.user_deposits(address).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetDeposit(args)) => {
+            interact.deposit(args.deposit_id).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetDepositOwner(args)) => {
+            interact.deposit_owner(args.deposit_id).await;
+        }
+        Some(interact_cli::InteractCliCommand::GetDepositFunder(args)) => {
+            interact.deposit_funder(args.deposit_id).await;
+        }
         None => {}
     }
 }
@@ -113,8 +512,21 @@ impl PingPongInteract {
         ping_amount: RustBigUint,
         duration_in_seconds: u64,
         token_id: String,
+        activation_window: Option<(u64, u64)>,
+        max_ping_amount: Option<RustBigUint>,
+        treasury: Option<Bech32Address>,
     ) {
-        let new_address = self
+        // optional arguments are positional, so the defaults are sent explicitly before a later one
+        let max_ping_amount = match (max_ping_amount, &treasury) {
+            (None, Some(_)) => Some(RustBigUint::default()),
+            (max_ping_amount, _) => max_ping_amount,
+        };
+        let activation_window = match (activation_window, &max_ping_amount) {
+            (None, Some(_)) => Some((0, 0)),
+            (activation_window, _) => activation_window,
+        };
+
+        let (new_address, logs) = self
             .interactor
             .tx()
             .from(&self.alice_wallet_address)
@@ -124,9 +536,13 @@ impl PingPongInteract {
                 ping_amount,
                 duration_in_seconds,
                 OptionalValue::Some(get_token_identifier(token_id)),
+                OptionalValue::from(activation_window.map(MultiValue2::from)),
+                OptionalValue::from(max_ping_amount),
+                OptionalValue::from(treasury),
             )
             .code(PING_PONG_CODE)
             .returns(ReturnsNewAddress)
+            .returns(ReturnsLogs)
             .run()
             .await;
         let new_address_bech32 = bech32::encode(&new_address);
@@ -136,9 +552,27 @@ impl PingPongInteract {
             ));
 
         println!("new address: {new_address_bech32}");
+        print_events(&PingPongEvent::from_logs(&logs));
     }
 
-    pub async fn upgrade(&mut self, ping_amount: RustBigUint, duration_in_seconds: u64) {
+    pub async fn upgrade(
+        &mut self,
+        ping_amount: RustBigUint,
+        duration_in_seconds: u64,
+        activation_window: Option<(u64, u64)>,
+        max_ping_amount: Option<RustBigUint>,
+        treasury: Option<Bech32Address>,
+    ) {
+        // optional arguments are positional, so the current values are sent explicitly before a later one
+        let max_ping_amount = match (max_ping_amount, &treasury) {
+            (None, Some(_)) => Some(self.max_ping_amount().await),
+            (max_ping_amount, _) => max_ping_amount,
+        };
+        let activation_window = match (activation_window, &max_ping_amount) {
+            (None, Some(_)) => Some(self.activation_window().await),
+            (activation_window, _) => activation_window,
+        };
+
         let upgrade_address = self
             .interactor
             .tx()
@@ -146,7 +580,13 @@ impl PingPongInteract {
             .to(self.state.current_ping_pong_address())
             .gas(30_000_000u64)
             .typed(ping_pong_proxy::PingPongProxy)
-            .upgrade(ping_amount, duration_in_seconds)
+            .upgrade(
+                ping_amount,
+                duration_in_seconds,
+                OptionalValue::from(activation_window.map(MultiValue2::from)),
+                OptionalValue::from(max_ping_amount),
+                OptionalValue::from(treasury),
+            )
             .code(PING_PONG_CODE)
             .returns(ReturnsNewAddress)
             .run()
@@ -167,8 +607,9 @@ impl PingPongInteract {
         nonce: u64,
         amount: u64,
         sender: &Bech32Address,
+        tier_id: Option<u64>,
         message: Option<&str>,
-    ) {
+    ) -> Vec<PingPongEvent> {
         let response = self
             .interactor
             .tx()
@@ -176,26 +617,42 @@ impl PingPongInteract {
             .to(self.state.current_ping_pong_address())
             .gas(30_000_000u64)
             .typed(ping_pong_proxy::PingPongProxy)
-            .ping()
+            .ping(OptionalValue::from(tier_id))
             .payment(EgldOrEsdtTokenPayment::new(
                 get_token_identifier(token_id),
                 nonce,
                 BigUint::from(amount),
             ))
-            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
             .run()
             .await;
 
         match response {
-            Ok(_) => println!("Ping successfully executed"),
+            Ok(logs) => {
+                println!("Ping successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
             Err(err) => {
                 println!("Ping failed with message: {}", err.message);
                 assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
             }
         }
     }
 
-    pub async fn pong(&mut self, sender: &Bech32Address, message: Option<&str>) {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ping_for(
+        &mut self,
+        token_id: String,
+        nonce: u64,
+        amount: u64,
+        sender: &Bech32Address,
+        beneficiary: Bech32Address,
+        tier_id: Option<u64>,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
         let response = self
             .interactor
             .tx()
@@ -203,111 +660,2028 @@ impl PingPongInteract {
             .to(self.state.current_ping_pong_address())
             .gas(30_000_000u64)
             .typed(ping_pong_proxy::PingPongProxy)
-            .pong()
-            .returns(ReturnsHandledOrError::new())
+            .ping_for(beneficiary, OptionalValue::from(tier_id))
+            .payment(EgldOrEsdtTokenPayment::new(
+                get_token_identifier(token_id),
+                nonce,
+                BigUint::from(amount),
+            ))
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
             .run()
             .await;
 
         match response {
-            Ok(_) => println!("Pong successfully executed"),
+            Ok(logs) => {
+                println!("Ping for successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
             Err(err) => {
-                println!("Pong failed with message: {}", err.message);
+                println!("Ping for failed with message: {}", err.message);
                 assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
             }
         }
     }
 
-    pub async fn did_user_ping(&mut self, address: Bech32Address) -> bool {
-        self.interactor
-            .query()
+    pub async fn pong(
+        &mut self,
+        sender: &Bech32Address,
+        deposit_id: Option<u64>,
+        receipt_nonces: &[u64],
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let receipts = self.receipt_payments(receipt_nonces).await;
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
             .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
             .typed(ping_pong_proxy::PingPongProxy)
-            .did_user_ping(address)
-            .returns(ReturnsResultUnmanaged)
+            .pong(OptionalValue::from(deposit_id))
+            .payment(receipts)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
             .run()
-            .await
-    }
+            .await;
 
-    pub async fn get_pong_enable_timestamp(&mut self, address: Bech32Address) -> u64 {
-        self.interactor
-            .query()
-            .to(self.state.current_ping_pong_address())
-            .typed(ping_pong_proxy::PingPongProxy)
-            .get_pong_enable_timestamp(address)
-            .returns(ReturnsResultUnmanaged)
-            .run()
-            .await
+        match response {
+            Ok(logs) => {
+                println!("Pong successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Pong failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
     }
 
-    pub async fn get_time_to_pong(&mut self, address: Bech32Address) -> Option<u64> {
-        let result_value = self
+    pub async fn pong_partial(
+        &mut self,
+        sender: &Bech32Address,
+        amount: RustBigUint,
+        deposit_id: Option<u64>,
+        receipt_nonces: &[u64],
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let receipts = self.receipt_payments(receipt_nonces).await;
+        let response = self
             .interactor
-            .query()
+            .tx()
+            .from(sender)
             .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
             .typed(ping_pong_proxy::PingPongProxy)
-            .get_time_to_pong(address)
-            .returns(ReturnsResultUnmanaged)
+            .pong_partial(amount, OptionalValue::from(deposit_id))
+            .payment(receipts)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
             .run()
             .await;
 
-        match result_value {
-            OptionalValue::Some(time) => Some(time),
-            OptionalValue::None => {
-                println!("Address unavailable");
-                None
+        match response {
+            Ok(logs) => {
+                println!("Pong partial successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Pong partial failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
             }
         }
     }
 
-    pub async fn accepted_payment_token_id(&mut self) -> String {
-        let result_value = self
+    pub async fn extend_lock(
+        &mut self,
+        sender: &Bech32Address,
+        extra_seconds: u64,
+        deposit_id: Option<u64>,
+        receipt_nonces: &[u64],
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let receipts = self.receipt_payments(receipt_nonces).await;
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .extend_lock(extra_seconds, OptionalValue::from(deposit_id))
+            .payment(receipts)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Extend lock successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Extend lock failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn set_auto_renew(
+        &mut self,
+        sender: &Bech32Address,
+        enabled: bool,
+        deposit_id: Option<u64>,
+        receipt_nonces: &[u64],
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let receipts = self.receipt_payments(receipt_nonces).await;
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_auto_renew(enabled, OptionalValue::from(deposit_id))
+            .payment(receipts)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Set auto-renew successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Set auto-renew failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn early_pong(
+        &mut self,
+        sender: &Bech32Address,
+        deposit_id: Option<u64>,
+        receipt_nonces: &[u64],
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let receipts = self.receipt_payments(receipt_nonces).await;
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .early_pong(OptionalValue::from(deposit_id))
+            .payment(receipts)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Early pong successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Early pong failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn pong_to(
+        &mut self,
+        sender: &Bech32Address,
+        destination: Bech32Address,
+        deposit_id: Option<u64>,
+        receipt_nonces: &[u64],
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let receipts = self.receipt_payments(receipt_nonces).await;
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pong_to(destination, OptionalValue::from(deposit_id))
+            .payment(receipts)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Pong to successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Pong to failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn pong_matured(
+        &mut self,
+        sender: &Bech32Address,
+        max_count: u64,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pong_matured(max_count)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Pong matured successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Pong matured failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn emergency_refund(
+        &mut self,
+        sender: &Bech32Address,
+        max_count: u64,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .emergency_refund(max_count)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Emergency refund successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Emergency refund failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn pong_all(
+        &mut self,
+        sender: &Bech32Address,
+        receipt_nonces: &[u64],
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let receipts = self.receipt_payments(receipt_nonces).await;
+        let response = self
+            .interactor
+            .tx()
+            .from(sender)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pong_all()
+            .payment(receipts)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Pong all successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Pong all failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn issue_receipt_token(
+        &mut self,
+        issue_cost: RustBigUint,
+        token_display_name: &str,
+        token_ticker: &str,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(100_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .issue_receipt_token(token_display_name, token_ticker)
+            .egld(BigUint::from(issue_cost))
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Issue receipt token successfully executed"),
+            Err(err) => {
+                println!("Issue receipt token failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_receipt_transferable(&mut self, transferable: bool, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(100_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_receipt_transferable(transferable)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set receipt transferable successfully executed"),
+            Err(err) => {
+                println!(
+                    "Set receipt transferable failed with message: {}",
+                    err.message
+                );
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_multiple_deposits(&mut self, enabled: bool, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_multiple_deposits(enabled)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set multiple deposits successfully executed"),
+            Err(err) => {
+                println!("Set multiple deposits failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_ping_for_ponger(
+        &mut self,
+        ponger: ping_pong_proxy::PingForPonger,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_ping_for_ponger(ponger)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set ping for ponger successfully executed"),
+            Err(err) => {
+                println!("Set ping for ponger failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_lock_tier(
+        &mut self,
+        tier_id: u64,
+        duration_in_seconds: u64,
+        reward_multiplier: Option<u64>,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_lock_tier(
+                tier_id,
+                duration_in_seconds,
+                OptionalValue::from(reward_multiplier),
+            )
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set lock tier successfully executed"),
+            Err(err) => {
+                println!("Set lock tier failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn remove_lock_tier(&mut self, tier_id: u64, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .remove_lock_tier(tier_id)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Remove lock tier successfully executed"),
+            Err(err) => {
+                println!("Remove lock tier failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_auto_renew_grace_period(&mut self, grace_period: u64, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_auto_renew_grace_period(grace_period)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set auto-renew grace period successfully executed"),
+            Err(err) => {
+                println!(
+                    "Set auto-renew grace period failed with message: {}",
+                    err.message
+                );
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_config_timelock(&mut self, delay_in_seconds: u64, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_config_timelock(delay_in_seconds)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set config timelock successfully executed"),
+            Err(err) => {
+                println!("Set config timelock failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn propose_config(
+        &mut self,
+        ping_amount: RustBigUint,
+        duration_in_seconds: u64,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .propose_config(BigUint::from(ping_amount), duration_in_seconds)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Propose config successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Propose config failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn execute_config_proposal(
+        &mut self,
+        proposal_id: u64,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .execute_config_proposal(proposal_id)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Execute config proposal successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!(
+                    "Execute config proposal failed with message: {}",
+                    err.message
+                );
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn cancel_config_proposal(
+        &mut self,
+        proposal_id: u64,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .cancel_config_proposal(proposal_id)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Cancel config proposal successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!(
+                    "Cancel config proposal failed with message: {}",
+                    err.message
+                );
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn set_partial_pong_remainder(
+        &mut self,
+        remainder: ping_pong_proxy::PartialPongRemainder,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_partial_pong_remainder(remainder)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set partial pong remainder successfully executed"),
+            Err(err) => {
+                println!(
+                    "Set partial pong remainder failed with message: {}",
+                    err.message
+                );
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_early_pong_penalty(
+        &mut self,
+        penalty_type: ping_pong_proxy::PenaltyType,
+        penalty_rate: u64,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_early_pong_penalty(penalty_type, penalty_rate)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set early pong penalty successfully executed"),
+            Err(err) => {
+                println!(
+                    "Set early pong penalty failed with message: {}",
+                    err.message
+                );
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_ping_fee(
+        &mut self,
+        fee_type: ping_pong_proxy::PingFeeType,
+        fee: RustBigUint,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_ping_fee(fee_type, BigUint::from(fee))
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set ping fee successfully executed"),
+            Err(err) => {
+                println!("Set ping fee failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_fee_collector(&mut self, fee_collector: Bech32Address, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_fee_collector(fee_collector)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set fee collector successfully executed"),
+            Err(err) => {
+                println!("Set fee collector failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn claim_fees(&mut self, message: Option<&str>) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .claim_fees()
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Claim fees successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Claim fees failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn fund_rewards(
+        &mut self,
+        token_id: String,
+        nonce: u64,
+        amount: u64,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .fund_rewards()
+            .payment(EgldOrEsdtTokenPayment::new(
+                get_token_identifier(token_id),
+                nonce,
+                BigUint::from(amount),
+            ))
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Fund rewards successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Fund rewards failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn set_reward_config(
+        &mut self,
+        reward_rate: u64,
+        empty_pool_policy: ping_pong_proxy::EmptyPoolPolicy,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_reward_config(reward_rate, empty_pool_policy)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set reward config successfully executed"),
+            Err(err) => {
+                println!("Set reward config failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn add_accepted_token(
+        &mut self,
+        token_id: String,
+        ping_amount: RustBigUint,
+        max_ping_amount: Option<RustBigUint>,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .add_accepted_token(
+                get_token_identifier(token_id),
+                ping_amount,
+                OptionalValue::from(max_ping_amount),
+            )
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Add accepted token successfully executed"),
+            Err(err) => {
+                println!("Add accepted token failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn remove_accepted_token(&mut self, token_id: String, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .remove_accepted_token(get_token_identifier(token_id))
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Remove accepted token successfully executed"),
+            Err(err) => {
+                println!("Remove accepted token failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn set_accepted_token_nonce(
+        &mut self,
+        token_id: String,
+        nonce: u64,
+        message: Option<&str>,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .set_accepted_token_nonce(get_token_identifier(token_id), nonce)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Set accepted token nonce successfully executed"),
+            Err(err) => {
+                println!(
+                    "Set accepted token nonce failed with message: {}",
+                    err.message
+                );
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn grant_role(
+        &mut self,
+        role: Role,
+        address: Bech32Address,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .grant_role(role, address)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Grant role successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Grant role failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn revoke_role(
+        &mut self,
+        role: Role,
+        address: Bech32Address,
+        message: Option<&str>,
+    ) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .revoke_role(role, address)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Revoke role successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Revoke role failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn enter_emergency(&mut self, message: Option<&str>) -> Vec<PingPongEvent> {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .enter_emergency()
+            .returns(ReturnsHandledOrError::new().returns(ReturnsLogs))
+            .run()
+            .await;
+
+        match response {
+            Ok(logs) => {
+                println!("Enter emergency successfully executed");
+                let events = PingPongEvent::from_logs(&logs);
+                print_events(&events);
+                events
+            }
+            Err(err) => {
+                println!("Enter emergency failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+                Vec::new()
+            }
+        }
+    }
+
+    pub async fn pause(&mut self, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pause()
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Pause successfully executed"),
+            Err(err) => {
+                println!("Pause failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn unpause(&mut self, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .unpause()
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Unpause successfully executed"),
+            Err(err) => {
+                println!("Unpause failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn pause_pong(&mut self, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pause_pong()
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Pause pong successfully executed"),
+            Err(err) => {
+                println!("Pause pong failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn unpause_pong(&mut self, message: Option<&str>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.alice_wallet_address)
+            .to(self.state.current_ping_pong_address())
+            .gas(30_000_000u64)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .unpause_pong()
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match response {
+            Ok(_) => println!("Unpause pong successfully executed"),
+            Err(err) => {
+                println!("Unpause pong failed with message: {}", err.message);
+                assert_eq!(message.unwrap_or_default(), err.message);
+            }
+        }
+    }
+
+    pub async fn is_paused(&mut self) -> bool {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .ping_paused()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn is_multiple_deposits_enabled(&mut self) -> bool {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .multiple_deposits_enabled()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn is_pong_paused(&mut self) -> bool {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pong_paused()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn did_user_ping(&mut self, address: Bech32Address) -> bool {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .did_user_ping(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn get_pong_enable_timestamp(&mut self, address: Bech32Address) -> u64 {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_pong_enable_timestamp(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn get_time_to_pong(&mut self, address: Bech32Address) -> Option<u64> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_time_to_pong(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match result_value {
+            OptionalValue::Some(time) => Some(time),
+            OptionalValue::None => {
+                println!("Address unavailable");
+                None
+            }
+        }
+    }
+
+    pub async fn accepted_payment_token_id(&mut self) -> String {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .accepted_payment_token_id()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        token_id_to_string(result_value)
+    }
+
+    pub async fn ping_amount(&mut self) -> RustBigUint {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_ping_amount()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn max_ping_amount(&mut self) -> RustBigUint {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_max_ping_amount()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn accepted_tokens(&mut self) -> Vec<(String, RustBigUint, RustBigUint)> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_accepted_tokens()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        result_value
+            .into_vec()
+            .into_iter()
+            .map(|entry| {
+                let (token_id, ping_amount, max_ping_amount) = entry.into_tuple();
+                let token_id = token_id_to_string(token_id);
+                println!("{token_id}: {ping_amount} (max {max_ping_amount})");
+                (token_id, ping_amount, max_ping_amount)
+            })
+            .collect()
+    }
+
+    pub async fn duration_in_seconds(&mut self) -> u64 {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .duration_in_seconds()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn user_ping_timestamp(&mut self, address: Bech32Address) -> u64 {
+        self.interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_user_ping_timestamp(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+    }
+
+    pub async fn activation_window(&mut self) -> (u64, u64) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_activation_window()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let (ping_start_timestamp, ping_end_timestamp) = result_value.into_tuple();
+        println!("Ping is open between {ping_start_timestamp} and {ping_end_timestamp}");
+        (ping_start_timestamp, ping_end_timestamp)
+    }
+
+    pub async fn has_role(&mut self, role: Role, address: Bech32Address) -> bool {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .has_role(role, address.clone())
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("{address} has role {role:?}: {result_value}");
+        result_value
+    }
+
+    pub async fn role_members(&mut self, role: Role) -> Vec<Bech32Address> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_role_members(role)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let members: Vec<Bech32Address> = result_value
+            .into_vec()
+            .into_iter()
+            .map(Bech32Address::from)
+            .collect();
+        for member in &members {
+            println!("{role:?}: {member}");
+        }
+        members
+    }
+
+    pub async fn is_emergency(&mut self) -> bool {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .emergency()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Emergency: {result_value}");
+        result_value
+    }
+
+    pub async fn emergency_timestamp(&mut self) -> Option<u64> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_emergency_timestamp()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let emergency_timestamp = result_value.into_option();
+        match emergency_timestamp {
+            Some(timestamp) => println!("Emergency entered at: {timestamp}"),
+            None => println!("Not in emergency mode"),
+        }
+        emergency_timestamp
+    }
+
+    pub async fn emergency_refund_cursor(&mut self) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .emergency_refund_cursor()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Emergency refund cursor: {result_value}");
+        result_value
+    }
+
+    pub async fn pong_matured_cursor(&mut self) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pong_matured_cursor()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Pong matured cursor: {result_value}");
+        result_value
+    }
+
+    pub async fn active_user_count(&mut self) -> usize {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_active_user_count()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Active users: {result_value}");
+        result_value
+    }
+
+    pub async fn active_users(
+        &mut self,
+        from: usize,
+        size: usize,
+    ) -> Vec<(Bech32Address, RustBigUint, u64)> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_active_users(from, size)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        result_value
+            .into_vec()
+            .into_iter()
+            .map(|entry| {
+                let (address, amount, unlock_timestamp) = entry.into_tuple();
+                println!(
+                    "{}: {amount} until {unlock_timestamp}",
+                    Bech32Address::from(&address)
+                );
+                (address.into(), amount, unlock_timestamp)
+            })
+            .collect()
+    }
+
+    pub async fn stats(&mut self) -> ping_pong_proxy::Stats<StaticApi> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_stats()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!("Stats: {result_value:?}");
+        result_value
+    }
+
+    pub async fn user_deposit(
+        &mut self,
+        address: Bech32Address,
+    ) -> Option<ping_pong_proxy::DepositInfo<StaticApi>> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_user_deposit(address)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        match result_value {
+            OptionalValue::Some(deposit) => {
+                println!("Deposit: {deposit:?}");
+                Some(deposit)
+            }
+            OptionalValue::None => {
+                println!("Address unavailable");
+                None
+            }
+        }
+    }
+
+    pub async fn user_deposits(
+        &mut self,
+        address: Bech32Address,
+    ) -> Vec<(u64, ping_pong_proxy::DepositInfo<StaticApi>)> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_user_deposits(address)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        result_value
+            .into_iter()
+            .map(|entry| {
+                let (deposit_id, deposit) = entry.into_tuple();
+                println!("Deposit {deposit_id}: {deposit:?}");
+                (deposit_id, deposit)
+            })
+            .collect()
+    }
+
+    pub async fn deposit(
+        &mut self,
+        deposit_id: u64,
+    ) -> Option<ping_pong_proxy::DepositInfo<StaticApi>> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_deposit(deposit_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        match result_value {
+            OptionalValue::Some(deposit) => {
+                println!("Deposit: {deposit:?}");
+                Some(deposit)
+            }
+            OptionalValue::None => {
+                println!("Deposit unavailable");
+                None
+            }
+        }
+    }
+
+    pub async fn config_timelock(&mut self) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_config_timelock()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Config timelock: {result_value}s");
+        result_value
+    }
+
+    pub async fn pending_config_timelock(
+        &mut self,
+    ) -> Option<ping_pong_proxy::ConfigTimelockChange> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_pending_config_timelock()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        match result_value {
+            OptionalValue::Some(change) => {
+                println!("Pending config timelock: {change:?}");
+                Some(change)
+            }
+            OptionalValue::None => {
+                println!("No pending config timelock");
+                None
+            }
+        }
+    }
+
+    /// Returns the pending proposals as (proposal id, ping amount, duration in seconds, executable timestamp)
+    pub async fn pending_config_proposals(&mut self) -> Vec<(u64, RustBigUint, u64, u64)> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_pending_config_proposals()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        result_value
+            .into_iter()
+            .map(|entry| {
+                let (proposal_id, proposal) = entry.into_tuple();
+                let ping_amount = RustBigUint::from_bytes_be(
+                    proposal.ping_amount.to_bytes_be().as_slice(),
+                );
+                println!(
+                    "Proposal {proposal_id}: ping amount {ping_amount}, duration {}s, executable at {}",
+                    proposal.duration_in_seconds, proposal.executable_timestamp
+                );
+                (
+                    proposal_id,
+                    ping_amount,
+                    proposal.duration_in_seconds,
+                    proposal.executable_timestamp,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns (tier id, duration in seconds, reward multiplier) for each lock tier, the default tier first
+    pub async fn lock_tiers(&mut self) -> Vec<(u64, u64, u64)> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_lock_tiers()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        result_value
+            .into_vec()
+            .into_iter()
+            .map(|entry| {
+                let (tier_id, duration_in_seconds, reward_multiplier) = entry.into_tuple();
+                println!("Tier {tier_id}: {duration_in_seconds}s (multiplier {reward_multiplier})");
+                (tier_id, duration_in_seconds, reward_multiplier)
+            })
+            .collect()
+    }
+
+    pub async fn deposit_lock_tier(
+        &mut self,
+        deposit_id: u64,
+    ) -> Option<ping_pong_proxy::LockTier> {
+        let result_value = self
             .interactor
             .query()
             .to(self.state.current_ping_pong_address())
             .typed(ping_pong_proxy::PingPongProxy)
-            .accepted_payment_token_id()
+            .get_deposit_lock_tier(deposit_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        match result_value {
+            OptionalValue::Some(lock_tier) => {
+                println!("Deposit lock tier: {lock_tier:?}");
+                Some(lock_tier)
+            }
+            OptionalValue::None => {
+                println!("Deposit unavailable");
+                None
+            }
+        }
+    }
+
+    pub async fn auto_renew_grace_period(&mut self) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .auto_renew_grace_period()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Auto-renew grace period: {result_value}");
+        result_value
+    }
+
+    pub async fn is_auto_renew_enabled(&mut self, deposit_id: u64) -> bool {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .deposit_auto_renew(deposit_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Auto-renew enabled: {result_value}");
+        result_value
+    }
+
+    pub async fn partial_pong_remainder(&mut self) -> ping_pong_proxy::PartialPongRemainder {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .partial_pong_remainder()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Partial pong remainder: {result_value:?}");
+        result_value
+    }
+
+    pub async fn ping_for_ponger(&mut self) -> ping_pong_proxy::PingForPonger {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .ping_for_ponger()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Ping for ponger: {result_value:?}");
+        result_value
+    }
+
+    pub async fn deposit_owner(&mut self, deposit_id: u64) -> Bech32Address {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .deposit_owner(deposit_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let owner = Bech32Address::from(result_value);
+        println!("Deposit owner: {owner}");
+        owner
+    }
+
+    pub async fn deposit_funder(&mut self, deposit_id: u64) -> Option<Bech32Address> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_deposit_funder(deposit_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        if result_value.is_egld() {
-            return EGLD.to_owned();
+        match result_value.into_option() {
+            Some(funder) => {
+                let funder = Bech32Address::from(funder);
+                println!("Deposit funder: {funder}");
+                Some(funder)
+            }
+            None => {
+                println!("Deposit funded by its owner");
+                None
+            }
         }
+    }
+
+    pub async fn early_pong_penalty_config(&mut self) -> (ping_pong_proxy::PenaltyType, u64) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_early_pong_penalty_config()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
 
-        result_value.into_esdt_option().unwrap().to_string()
+        let (penalty_type, penalty_rate) = result_value.into_tuple();
+        println!("Early pong penalty: {penalty_type:?} {penalty_rate}");
+        (penalty_type, penalty_rate)
     }
 
-    pub async fn ping_amount(&mut self) -> RustBigUint {
-        self.interactor
+    pub async fn treasury(&mut self) -> Option<Bech32Address> {
+        let result_value = self
+            .interactor
             .query()
             .to(self.state.current_ping_pong_address())
             .typed(ping_pong_proxy::PingPongProxy)
-            .ping_amount()
+            .get_treasury()
             .returns(ReturnsResultUnmanaged)
             .run()
-            .await
+            .await;
+
+        match result_value.into_option() {
+            Some(treasury) => {
+                let treasury = Bech32Address::from(treasury);
+                println!("Treasury: {treasury}");
+                Some(treasury)
+            }
+            None => {
+                println!("No treasury set");
+                None
+            }
+        }
     }
 
-    pub async fn duration_in_seconds(&mut self) -> u64 {
-        self.interactor
+    pub async fn ping_fee_config(&mut self) -> (ping_pong_proxy::PingFeeType, RustBigUint) {
+        let result_value = self
+            .interactor
             .query()
             .to(self.state.current_ping_pong_address())
             .typed(ping_pong_proxy::PingPongProxy)
-            .duration_in_seconds()
+            .get_ping_fee_config()
             .returns(ReturnsResultUnmanaged)
             .run()
-            .await
+            .await;
+
+        let (fee_type, fee) = result_value.into_tuple();
+        println!("Ping fee: {fee_type:?} {fee}");
+        (fee_type, fee)
     }
 
-    pub async fn user_ping_timestamp(&mut self, address: Bech32Address) -> u64 {
-        self.interactor
+    pub async fn fee_collector(&mut self) -> Option<Bech32Address> {
+        let result_value = self
+            .interactor
             .query()
             .to(self.state.current_ping_pong_address())
             .typed(ping_pong_proxy::PingPongProxy)
-            .user_ping_timestamp(address)
+            .get_fee_collector()
             .returns(ReturnsResultUnmanaged)
             .run()
-            .await
+            .await;
+
+        match result_value.into_option() {
+            Some(fee_collector) => {
+                let fee_collector = Bech32Address::from(fee_collector);
+                println!("Fee collector: {fee_collector}");
+                Some(fee_collector)
+            }
+            None => {
+                println!("No fee collector set");
+                None
+            }
+        }
+    }
+
+    pub async fn accumulated_fees(&mut self) -> Vec<(String, RustBigUint)> {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_accumulated_fees()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        result_value
+            .into_vec()
+            .into_iter()
+            .map(|entry| {
+                let (token_id, amount) = entry.into_tuple();
+                let token_id = token_id_to_string(token_id);
+                println!("{token_id}: {amount}");
+                (token_id, amount)
+            })
+            .collect()
+    }
+
+    pub async fn early_pong_penalty(&mut self, address: Bech32Address) -> RustBigUint {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_early_pong_penalty(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Early pong penalty: {result_value}");
+        result_value
+    }
+
+    pub async fn receipt_token_id(&mut self) -> String {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .receipt_token()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let token_id = result_value.to_string();
+        println!("Receipt token: {token_id}");
+        token_id
+    }
+
+    pub async fn is_receipt_transferable(&mut self) -> bool {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .receipt_transferable()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Receipt transferable: {result_value}");
+        result_value
+    }
+
+    pub async fn accepted_token_nonce(&mut self, token_id: String) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .token_nonce(get_token_identifier(token_id))
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Accepted token nonce: {result_value}");
+        result_value
+    }
+
+    pub async fn deposit_token_nonce(&mut self, deposit_id: u64) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .deposit_token_nonce(deposit_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Deposit token nonce: {result_value}");
+        result_value
+    }
+
+    pub async fn deposit_receipt_nonce(&mut self, deposit_id: u64) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .deposit_receipt_nonce(deposit_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Receipt nonce: {result_value}");
+        result_value
+    }
+
+    pub async fn receipt_deposit_id(&mut self, receipt_nonce: u64) -> u64 {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .receipt_deposit_id(receipt_nonce)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Receipt deposit id: {result_value}");
+        result_value
+    }
+
+    /// The receipts to send along with a `pong`, empty when the deposits have none.
+    async fn receipt_payments(&mut self, receipt_nonces: &[u64]) -> MultiEsdtPayment<StaticApi> {
+        let mut payments = MultiEsdtPayment::new();
+        if receipt_nonces.is_empty() {
+            return payments;
+        }
+
+        let receipt_token_id = self.receipt_token_id().await;
+        for receipt_nonce in receipt_nonces {
+            payments.push(EsdtTokenPayment::new(
+                TokenIdentifier::from(receipt_token_id.as_str()),
+                *receipt_nonce,
+                BigUint::from(1u32),
+            ));
+        }
+
+        payments
+    }
+
+    pub async fn reward_config(&mut self) -> (u64, ping_pong_proxy::EmptyPoolPolicy) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_reward_config()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let (reward_rate, empty_pool_policy) = result_value.into_tuple();
+        println!("Reward config: {reward_rate} {empty_pool_policy:?}");
+        (reward_rate, empty_pool_policy)
+    }
+
+    pub async fn reward_pool(&mut self, token_id: String) -> RustBigUint {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .reward_pool(get_token_identifier(token_id))
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Reward pool: {result_value}");
+        result_value
+    }
+
+    pub async fn pending_reward(&mut self, address: Bech32Address) -> RustBigUint {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_ping_pong_address())
+            .typed(ping_pong_proxy::PingPongProxy)
+            .get_pending_reward(address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Pending reward: {result_value}");
+        result_value
     }
 }
 
@@ -318,3 +2692,17 @@ fn get_token_identifier(token_id: String) -> EgldOrEsdtTokenIdentifier<StaticApi
         EgldOrEsdtTokenIdentifier::esdt(&token_id)
     }
 }
+
+fn token_id_to_string(token_id: EgldOrEsdtTokenIdentifier<StaticApi>) -> String {
+    if token_id.is_egld() {
+        return EGLD.to_owned();
+    }
+
+    token_id.into_esdt_option().unwrap().to_string()
+}
+
+fn print_events(events: &[PingPongEvent]) {
+    for event in events {
+        println!("event: {event:?}");
+    }
+}
@@ -1,5 +1,5 @@
-use clap::{Args, Parser, Subcommand};
-use multiversx_sc_snippets::imports::RustBigUint;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use multiversx_sc_snippets::imports::{Bech32Address, RustBigUint};
 
 /// Ping Pong Interact CLI
 #[derive(Default, PartialEq, Eq, Debug, Parser)]
@@ -22,8 +22,291 @@ pub enum InteractCliCommand {
         about = "User sends some EGLD to be locked in the contract for a period of time."
     )]
     Ping(PingArgs),
+    #[command(
+        name = "ping-for",
+        about = "Funder locks some tokens under a beneficiary's address."
+    )]
+    PingFor(PingForArgs),
     #[command(name = "pong", about = "User can take back funds from the contract.")]
-    Pong,
+    Pong(PongArgs),
+    #[command(
+        name = "pong-to",
+        about = "User takes back funds from the contract to another address."
+    )]
+    PongTo(PongToArgs),
+    #[command(
+        name = "pong-partial",
+        about = "User takes back part of an unlocked deposit, leaving the rest locked in the contract."
+    )]
+    PongPartial(PongPartialArgs),
+    #[command(
+        name = "early-pong",
+        about = "User takes back funds before the deadline, minus a penalty."
+    )]
+    EarlyPong(PongArgs),
+    #[command(
+        name = "pong-all",
+        about = "User takes back every deposit that can already be ponged."
+    )]
+    PongAll(PongAllArgs),
+    #[command(
+        name = "pong-matured",
+        about = "Refunds the matured deposits to their owners, resuming from where the previous call stopped."
+    )]
+    PongMatured(PongMaturedArgs),
+    #[command(
+        name = "emergency-refund",
+        about = "Refunds the deposits right away in emergency mode, resuming from where the previous call stopped."
+    )]
+    EmergencyRefund(EmergencyRefundArgs),
+    #[command(
+        name = "extend-lock",
+        about = "User pushes back the unlock time of a deposit."
+    )]
+    ExtendLock(ExtendLockArgs),
+    #[command(
+        name = "set-auto-renew",
+        about = "User opts a deposit in or out of being locked again when not ponged within the grace period."
+    )]
+    SetAutoRenew(SetAutoRenewArgs),
+    #[command(
+        name = "issue-receipt-token",
+        about = "Issues the receipt NFT collection minted on ping and burned on pong."
+    )]
+    IssueReceiptToken(IssueReceiptTokenArgs),
+    #[command(
+        name = "set-receipt-transferable",
+        about = "Allows or restricts transfers of the receipts between users. Receipts are minted once this was called."
+    )]
+    SetReceiptTransferable(SetReceiptTransferableArgs),
+    #[command(
+        name = "set-multiple-deposits",
+        about = "Allows or disallows users to hold several deposits at once."
+    )]
+    SetMultipleDeposits(SetMultipleDepositsArgs),
+    #[command(
+        name = "set-ping-for-ponger",
+        about = "Sets who can pong a deposit made with ping-for."
+    )]
+    SetPingForPonger(SetPingForPongerArgs),
+    #[command(
+        name = "set-partial-pong-remainder",
+        about = "Sets whether what is left after a partial pong stays unlocked or is locked again."
+    )]
+    SetPartialPongRemainder(SetPartialPongRemainderArgs),
+    #[command(
+        name = "set-early-pong-penalty",
+        about = "Sets the penalty type and rate, in basis points, for early pong."
+    )]
+    SetEarlyPongPenalty(SetEarlyPongPenaltyArgs),
+    #[command(
+        name = "set-auto-renew-grace-period",
+        about = "Sets how long an unlocked auto-renewed deposit can be ponged before it is locked again, 0 to disable."
+    )]
+    SetAutoRenewGracePeriod(SetAutoRenewGracePeriodArgs),
+    #[command(
+        name = "set-config-timelock",
+        about = "Owner sets how long a config proposal waits before it can be executed. A decrease waits for the current timelock."
+    )]
+    SetConfigTimelock(SetConfigTimelockArgs),
+    #[command(
+        name = "propose-config",
+        about = "Proposes a new ping amount and duration, executable once the timelock has passed."
+    )]
+    ProposeConfig(ProposeConfigArgs),
+    #[command(
+        name = "execute-config-proposal",
+        about = "Applies a config proposal whose timelock has passed."
+    )]
+    ExecuteConfigProposal(ConfigProposalArgs),
+    #[command(
+        name = "cancel-config-proposal",
+        about = "Drops a pending config proposal."
+    )]
+    CancelConfigProposal(ConfigProposalArgs),
+    #[command(
+        name = "fund-rewards",
+        about = "Owner adds tokens to the pool paying rewards on pong."
+    )]
+    FundRewards(FundRewardsArgs),
+    #[command(
+        name = "set-reward-config",
+        about = "Sets the yearly reward rate, in basis points, and what pong does when the pool is empty."
+    )]
+    SetRewardConfig(SetRewardConfigArgs),
+    #[command(
+        name = "set-ping-fee",
+        about = "Sets the fee paid on top of each ping, as a rate in basis points or a flat amount."
+    )]
+    SetPingFee(SetPingFeeArgs),
+    #[command(
+        name = "set-fee-collector",
+        about = "Sets the address receiving the ping fees."
+    )]
+    SetFeeCollector(SetFeeCollectorArgs),
+    #[command(
+        name = "claim-fees",
+        about = "Sends the ping fees collected so far to the fee collector."
+    )]
+    ClaimFees,
+    #[command(
+        name = "set-lock-tier",
+        about = "Adds or updates a lock tier, with its duration and reward multiplier in basis points."
+    )]
+    SetLockTier(SetLockTierArgs),
+    #[command(
+        name = "remove-lock-tier",
+        about = "Stops accepting a lock tier for ping."
+    )]
+    RemoveLockTier(RemoveLockTierArgs),
+    #[command(
+        name = "add-token",
+        about = "Accepts a new token for ping, or updates its ping amount."
+    )]
+    AddAcceptedToken(AddAcceptedTokenArgs),
+    #[command(name = "remove-token", about = "Stops accepting a token for ping.")]
+    RemoveAcceptedToken(RemoveAcceptedTokenArgs),
+    #[command(
+        name = "set-token-nonce",
+        about = "Restricts ping with an NFT, SFT or meta-ESDT collection to a single nonce, 0 for any nonce."
+    )]
+    SetAcceptedTokenNonce(SetAcceptedTokenNonceArgs),
+    #[command(
+        name = "grant-role",
+        about = "Lets an address pause, or change the configuration, in place of the owner."
+    )]
+    GrantRole(RoleArgs),
+    #[command(name = "revoke-role", about = "Takes a role back from an address.")]
+    RevokeRole(RoleArgs),
+    #[command(
+        name = "enter-emergency",
+        about = "Blocks ping and lets every deposit be ponged right away. Cannot be undone."
+    )]
+    EnterEmergency,
+    #[command(name = "pause", about = "Pauses ping.")]
+    Pause,
+    #[command(name = "unpause", about = "Unpauses ping.")]
+    Unpause,
+    #[command(name = "pause-pong", about = "Pauses pong.")]
+    PausePong,
+    #[command(name = "unpause-pong", about = "Unpauses pong.")]
+    UnpausePong,
+    #[command(name = "is-paused", about = "Returns if ping is paused.")]
+    IsPaused,
+    #[command(name = "is-pong-paused", about = "Returns if pong is paused.")]
+    IsPongPaused,
+    #[command(
+        name = "is-multiple-deposits-enabled",
+        about = "Returns if users can hold several deposits at once."
+    )]
+    IsMultipleDepositsEnabled,
+    #[command(
+        name = "partial-pong-remainder",
+        about = "Returns whether what is left after a partial pong stays unlocked or is locked again."
+    )]
+    GetPartialPongRemainder,
+    #[command(
+        name = "early-pong-penalty-config",
+        about = "Returns the penalty type and rate for early pong."
+    )]
+    GetEarlyPongPenaltyConfig,
+    #[command(
+        name = "lock-tiers",
+        about = "Returns the lock tiers that can be picked on ping."
+    )]
+    GetLockTiers,
+    #[command(
+        name = "deposit-lock-tier",
+        about = "Returns the lock duration and reward multiplier of a deposit."
+    )]
+    GetDepositLockTier(GetDepositArgs),
+    #[command(
+        name = "config-timelock",
+        about = "Returns how long a config proposal made now waits before it can be executed."
+    )]
+    GetConfigTimelock,
+    #[command(
+        name = "pending-config-timelock",
+        about = "Returns the decrease of the config timelock that has not taken effect yet."
+    )]
+    GetPendingConfigTimelock,
+    #[command(
+        name = "pending-config-proposals",
+        about = "Returns the config proposals not executed nor cancelled yet."
+    )]
+    GetPendingConfigProposals,
+    #[command(
+        name = "auto-renew-grace-period",
+        about = "Returns how long an unlocked auto-renewed deposit can be ponged before it is locked again."
+    )]
+    GetAutoRenewGracePeriod,
+    #[command(
+        name = "is-auto-renew-enabled",
+        about = "Returns if a deposit is locked again when not ponged within the grace period."
+    )]
+    IsAutoRenewEnabled(GetDepositArgs),
+    #[command(
+        name = "treasury",
+        about = "Returns the address receiving the early pong penalties."
+    )]
+    GetTreasury,
+    #[command(
+        name = "ping-fee-config",
+        about = "Returns the type and value of the ping fee."
+    )]
+    GetPingFeeConfig,
+    #[command(
+        name = "fee-collector",
+        about = "Returns the address receiving the ping fees."
+    )]
+    GetFeeCollector,
+    #[command(
+        name = "accumulated-fees",
+        about = "Returns the ping fees not claimed yet, for each token."
+    )]
+    GetAccumulatedFees,
+    #[command(
+        name = "early-pong-penalty",
+        about = "Returns the penalty the user would pay to early pong now."
+    )]
+    GetEarlyPongPenalty(GetEarlyPongPenaltyArgs),
+    #[command(name = "receipt-token", about = "Returns the receipt token id.")]
+    GetReceiptTokenId,
+    #[command(
+        name = "is-receipt-transferable",
+        about = "Returns if receipts can be transferred between users."
+    )]
+    IsReceiptTransferable,
+    #[command(
+        name = "deposit-receipt-nonce",
+        about = "Returns the nonce of the receipt minted for a deposit"
+    )]
+    GetDepositReceiptNonce(GetDepositArgs),
+    #[command(
+        name = "receipt-deposit-id",
+        about = "Returns the deposit a receipt was minted for"
+    )]
+    GetReceiptDepositId(GetReceiptDepositIdArgs),
+    #[command(
+        name = "reward-config",
+        about = "Returns the reward rate and the empty pool policy."
+    )]
+    GetRewardConfig,
+    #[command(
+        name = "reward-pool",
+        about = "Returns the rewards left in the pool for a token."
+    )]
+    GetRewardPool(GetRewardPoolArgs),
+    #[command(
+        name = "pending-reward",
+        about = "Returns the reward accrued so far by the user's next deposit."
+    )]
+    GetPendingReward(GetPendingRewardArgs),
+    #[command(
+        name = "ping-for-ponger",
+        about = "Returns who can pong a deposit made with ping-for."
+    )]
+    GetPingForPonger,
     #[command(name = "did-user-ping", about = "Returns if a user ping-ed or not")]
     DidUserPing(DidUserPingArgs),
     #[command(
@@ -37,6 +320,26 @@ pub enum InteractCliCommand {
     GetAcceptedPaymentToken,
     #[command(name = "ping-amount", about = "Returns the ping amount.")]
     GetPingAmount,
+    #[command(
+        name = "max-ping-amount",
+        about = "Returns the max ping amount, zero in exact mode."
+    )]
+    GetMaxPingAmount,
+    #[command(
+        name = "tokens",
+        about = "Returns all accepted tokens with their ping amounts and max ping amounts."
+    )]
+    GetAcceptedTokens,
+    #[command(
+        name = "token-nonce",
+        about = "Returns the only nonce accepted for a token, 0 meaning any nonce."
+    )]
+    GetAcceptedTokenNonce(GetAcceptedTokenNonceArgs),
+    #[command(
+        name = "deposit-token-nonce",
+        about = "Returns the nonce of the token locked in a deposit."
+    )]
+    GetDepositTokenNonce(GetDepositArgs),
     #[command(name = "duration", about = "Returns the duration in seconds.")]
     GetDurationTimestamp,
     #[command(
@@ -44,6 +347,72 @@ pub enum InteractCliCommand {
         about = "Returns the timestamp at which the user pinged"
     )]
     GetUserPingTimestamp(GetUserPingTimestampArgs),
+    #[command(
+        name = "activation-window",
+        about = "Returns the start and end timestamps between which ping is accepted"
+    )]
+    GetActivationWindow,
+    #[command(
+        name = "active-user-count",
+        about = "Returns the number of users with funds locked"
+    )]
+    GetActiveUserCount,
+    #[command(
+        name = "has-role",
+        about = "Returns if the address was granted the role"
+    )]
+    HasRole(RoleArgs),
+    #[command(
+        name = "role-members",
+        about = "Returns the addresses granted the role"
+    )]
+    GetRoleMembers(GetRoleMembersArgs),
+    #[command(
+        name = "is-emergency",
+        about = "Returns if emergency mode was entered."
+    )]
+    IsEmergency,
+    #[command(
+        name = "emergency-timestamp",
+        about = "Returns when emergency mode was entered"
+    )]
+    GetEmergencyTimestamp,
+    #[command(
+        name = "emergency-refund-cursor",
+        about = "Returns the last deposit looked at by emergency-refund"
+    )]
+    GetEmergencyRefundCursor,
+    #[command(
+        name = "pong-matured-cursor",
+        about = "Returns the last deposit looked at by pong-matured"
+    )]
+    GetPongMaturedCursor,
+    #[command(
+        name = "active-users",
+        about = "Returns a page of the users with funds locked"
+    )]
+    GetActiveUsers(GetActiveUsersArgs),
+    #[command(
+        name = "stats",
+        about = "Returns the ping and pong counters and the totals per token"
+    )]
+    GetStats,
+    #[command(
+        name = "user-deposit",
+        about = "Returns the amount, token and timestamps of the user's deposit"
+    )]
+    GetUserDeposit(GetUserDepositArgs),
+    #[command(name = "user-deposits", about = "Returns all deposits of the user")]
+    GetUserDeposits(GetUserDepositsArgs),
+    #[command(name = "deposit", about = "Returns the deposit with the given id")]
+    GetDeposit(GetDepositArgs),
+    #[command(name = "deposit-owner", about = "Returns the owner of a deposit")]
+    GetDepositOwner(GetDepositArgs),
+    #[command(
+        name = "deposit-funder",
+        about = "Returns the funder of a deposit made with ping-for"
+    )]
+    GetDepositFunder(GetDepositArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -56,6 +425,30 @@ pub struct DeployArgs {
 
     #[arg(short = 't', long = "token-id", default_value = "EGLD")]
     pub token_id: String,
+
+    #[arg(long = "ping-start", requires = "ping_end")]
+    pub ping_start: Option<u64>,
+
+    #[arg(long = "ping-end", requires = "ping_start")]
+    pub ping_end: Option<u64>,
+
+    #[arg(long = "max-ping-amount")]
+    pub max_ping_amount: Option<RustBigUint>,
+
+    #[arg(long = "treasury")]
+    pub treasury: Option<String>,
+}
+
+impl DeployArgs {
+    pub fn activation_window(&self) -> Option<(u64, u64)> {
+        self.ping_start.zip(self.ping_end)
+    }
+
+    pub fn treasury(&self) -> Option<Bech32Address> {
+        self.treasury
+            .as_ref()
+            .map(|treasury| Bech32Address::from_bech32_string(treasury.clone()))
+    }
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -65,6 +458,30 @@ pub struct UpgradeArgs {
 
     #[arg(short = 'd', long = "duration-in-seconds")]
     pub duration_in_seconds: u64,
+
+    #[arg(long = "ping-start", requires = "ping_end")]
+    pub ping_start: Option<u64>,
+
+    #[arg(long = "ping-end", requires = "ping_start")]
+    pub ping_end: Option<u64>,
+
+    #[arg(long = "max-ping-amount")]
+    pub max_ping_amount: Option<RustBigUint>,
+
+    #[arg(long = "treasury")]
+    pub treasury: Option<String>,
+}
+
+impl UpgradeArgs {
+    pub fn activation_window(&self) -> Option<(u64, u64)> {
+        self.ping_start.zip(self.ping_end)
+    }
+
+    pub fn treasury(&self) -> Option<Bech32Address> {
+        self.treasury
+            .as_ref()
+            .map(|treasury| Bech32Address::from_bech32_string(treasury.clone()))
+    }
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -77,6 +494,327 @@ pub struct PingArgs {
 
     #[arg(short = 'a', long = "amount")]
     pub amount: u64,
+
+    #[arg(short = 'l', long = "tier-id")]
+    pub tier_id: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PingForArgs {
+    #[arg(short = 't', long = "token")]
+    pub token: String,
+
+    #[arg(short = 'n', long = "nonce")]
+    pub nonce: u64,
+
+    #[arg(short = 'a', long = "amount")]
+    pub amount: u64,
+
+    #[arg(short = 'b', long = "beneficiary")]
+    pub beneficiary: String,
+
+    #[arg(short = 'l', long = "tier-id")]
+    pub tier_id: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct FundRewardsArgs {
+    #[arg(short = 't', long = "token")]
+    pub token: String,
+
+    #[arg(short = 'n', long = "nonce")]
+    pub nonce: u64,
+
+    #[arg(short = 'a', long = "amount")]
+    pub amount: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetLockTierArgs {
+    #[arg(short = 'l', long = "tier-id")]
+    pub tier_id: u64,
+
+    #[arg(short = 'd', long = "duration")]
+    pub duration_in_seconds: u64,
+
+    #[arg(short = 'm', long = "reward-multiplier")]
+    pub reward_multiplier: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RemoveLockTierArgs {
+    #[arg(short = 'l', long = "tier-id")]
+    pub tier_id: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PongArgs {
+    #[arg(short = 'i', long = "deposit-id")]
+    pub deposit_id: Option<u64>,
+
+    #[arg(short = 'r', long = "receipt-nonce")]
+    pub receipt_nonce: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PongToArgs {
+    #[arg(short = 'd', long = "destination")]
+    pub destination: String,
+
+    #[arg(short = 'i', long = "deposit-id")]
+    pub deposit_id: Option<u64>,
+
+    #[arg(short = 'r', long = "receipt-nonce")]
+    pub receipt_nonce: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PongPartialArgs {
+    #[arg(short = 'a', long = "amount")]
+    pub amount: RustBigUint,
+
+    #[arg(short = 'i', long = "deposit-id")]
+    pub deposit_id: Option<u64>,
+
+    #[arg(short = 'r', long = "receipt-nonce")]
+    pub receipt_nonce: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PongAllArgs {
+    #[arg(short = 'r', long = "receipt-nonces", num_args = 0..)]
+    pub receipt_nonces: Vec<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PongMaturedArgs {
+    #[arg(short = 'm', long = "max-count")]
+    pub max_count: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct EmergencyRefundArgs {
+    #[arg(short = 'm', long = "max-count")]
+    pub max_count: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ExtendLockArgs {
+    #[arg(short = 's', long = "extra-seconds")]
+    pub extra_seconds: u64,
+
+    #[arg(short = 'i', long = "deposit-id")]
+    pub deposit_id: Option<u64>,
+
+    #[arg(short = 'r', long = "receipt-nonce")]
+    pub receipt_nonce: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetAutoRenewArgs {
+    #[arg(short = 'e', long = "enabled", action = ArgAction::Set)]
+    pub enabled: bool,
+
+    #[arg(short = 'i', long = "deposit-id")]
+    pub deposit_id: Option<u64>,
+
+    #[arg(short = 'r', long = "receipt-nonce")]
+    pub receipt_nonce: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetConfigTimelockArgs {
+    #[arg(short = 'd', long = "delay")]
+    pub delay_in_seconds: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ProposeConfigArgs {
+    #[arg(short = 'p', long = "ping-amount")]
+    pub ping_amount: RustBigUint,
+
+    #[arg(short = 'd', long = "duration")]
+    pub duration_in_seconds: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ConfigProposalArgs {
+    #[arg(short = 'i', long = "proposal-id")]
+    pub proposal_id: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetAutoRenewGracePeriodArgs {
+    #[arg(short = 'g', long = "grace-period")]
+    pub grace_period: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct IssueReceiptTokenArgs {
+    #[arg(short = 'c', long = "issue-cost")]
+    pub issue_cost: RustBigUint,
+
+    #[arg(short = 'n', long = "name")]
+    pub token_display_name: String,
+
+    #[arg(short = 't', long = "ticker")]
+    pub token_ticker: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetReceiptTransferableArgs {
+    #[arg(short = 't', long = "transferable", action = ArgAction::Set)]
+    pub transferable: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetMultipleDepositsArgs {
+    #[arg(short = 'e', long = "enabled", action = ArgAction::Set)]
+    pub enabled: bool,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Ponger {
+    #[default]
+    Beneficiary,
+    Funder,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetPingForPongerArgs {
+    #[arg(short = 'p', long = "ponger", value_enum)]
+    pub ponger: Ponger,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Remainder {
+    #[default]
+    KeepUnlocked,
+    Relock,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetPartialPongRemainderArgs {
+    #[arg(short = 'r', long = "remainder", value_enum)]
+    pub remainder: Remainder,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum PenaltyType {
+    #[default]
+    Flat,
+    Linear,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetEarlyPongPenaltyArgs {
+    #[arg(short = 't', long = "penalty-type", value_enum)]
+    pub penalty_type: PenaltyType,
+
+    #[arg(short = 'r', long = "penalty-rate")]
+    pub penalty_rate: u64,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum PingFeeType {
+    #[default]
+    Rate,
+    Flat,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetPingFeeArgs {
+    #[arg(short = 't', long = "fee-type", value_enum)]
+    pub fee_type: PingFeeType,
+
+    #[arg(short = 'f', long = "fee")]
+    pub fee: RustBigUint,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetFeeCollectorArgs {
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Role {
+    #[default]
+    Pauser,
+    ConfigManager,
+}
+
+impl From<Role> for crate::Role {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Pauser => crate::Role::Pauser,
+            Role::ConfigManager => crate::Role::ConfigManager,
+        }
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RoleArgs {
+    #[arg(short = 'r', long = "role", value_enum)]
+    pub role: Role,
+
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetRoleMembersArgs {
+    #[arg(short = 'r', long = "role", value_enum)]
+    pub role: Role,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum EmptyPoolPolicy {
+    #[default]
+    Fail,
+    PrincipalOnly,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetRewardConfigArgs {
+    #[arg(short = 'r', long = "reward-rate")]
+    pub reward_rate: u64,
+
+    #[arg(short = 'p', long = "empty-pool-policy", value_enum)]
+    pub empty_pool_policy: EmptyPoolPolicy,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AddAcceptedTokenArgs {
+    #[arg(short = 't', long = "token-id")]
+    pub token_id: String,
+
+    #[arg(short = 'p', long = "ping-amount")]
+    pub ping_amount: RustBigUint,
+
+    #[arg(long = "max-ping-amount")]
+    pub max_ping_amount: Option<RustBigUint>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RemoveAcceptedTokenArgs {
+    #[arg(short = 't', long = "token-id")]
+    pub token_id: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetAcceptedTokenNonceArgs {
+    #[arg(short = 't', long = "token-id")]
+    pub token_id: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetAcceptedTokenNonceArgs {
+    #[arg(short = 't', long = "token-id")]
+    pub token_id: String,
+
+    #[arg(short = 'n', long = "nonce")]
+    pub nonce: u64,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -102,3 +840,54 @@ pub struct GetUserPingTimestampArgs {
     #[arg(short = 'a', long = "address")]
     pub address: String,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetActiveUsersArgs {
+    #[arg(short = 'f', long = "from", default_value_t = 0)]
+    pub from: usize,
+
+    #[arg(short = 's', long = "size", default_value_t = 100)]
+    pub size: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetUserDepositArgs {
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetUserDepositsArgs {
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetDepositArgs {
+    #[arg(short = 'i', long = "deposit-id")]
+    pub deposit_id: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetEarlyPongPenaltyArgs {
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetRewardPoolArgs {
+    #[arg(short = 't', long = "token-id")]
+    pub token_id: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetPendingRewardArgs {
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GetReceiptDepositIdArgs {
+    #[arg(short = 'r', long = "receipt-nonce")]
+    pub receipt_nonce: u64,
+}
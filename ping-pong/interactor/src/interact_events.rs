@@ -0,0 +1,354 @@
+use multiversx_sc_snippets::{imports::*, multiversx_sc_scenario::scenario_model::Log};
+
+/// Ping Pong contract events, decoded from transaction logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PingPongEvent {
+    Ping {
+        user: Bech32Address,
+        deposit_id: u64,
+        token_id: String,
+        amount: RustBigUint,
+        ping_timestamp: u64,
+        unlock_timestamp: u64,
+    },
+    Pong {
+        user: Bech32Address,
+        receiver: Bech32Address,
+        deposit_id: u64,
+        token_id: String,
+        amount: RustBigUint,
+    },
+    PingFor {
+        funder: Bech32Address,
+        beneficiary: Bech32Address,
+        deposit_id: u64,
+    },
+    EarlyPong {
+        user: Bech32Address,
+        deposit_id: u64,
+        token_id: String,
+        penalty: RustBigUint,
+    },
+    PartialPong {
+        user: Bech32Address,
+        deposit_id: u64,
+        remaining_amount: RustBigUint,
+        unlock_timestamp: u64,
+    },
+    LockExtended {
+        user: Bech32Address,
+        deposit_id: u64,
+        unlock_timestamp: u64,
+    },
+    AutoRenew {
+        user: Bech32Address,
+        deposit_id: u64,
+        enabled: bool,
+    },
+    Reward {
+        user: Bech32Address,
+        deposit_id: u64,
+        token_id: String,
+        reward: RustBigUint,
+    },
+    Config {
+        token_id: String,
+        ping_amount: RustBigUint,
+        duration_in_seconds: u64,
+    },
+    SetAcceptedToken {
+        token_id: String,
+        ping_amount: RustBigUint,
+        max_ping_amount: RustBigUint,
+    },
+    AcceptedTokenNonce {
+        token_id: String,
+        nonce: u64,
+    },
+    RemoveAcceptedToken {
+        token_id: String,
+    },
+    ActivationWindow {
+        ping_start_timestamp: u64,
+        ping_end_timestamp: u64,
+    },
+    MultipleDeposits {
+        enabled: bool,
+    },
+    PingForPonger {
+        funder_pongs: bool,
+    },
+    EarlyPongPenalty {
+        linear: bool,
+        penalty_rate: u64,
+    },
+    Treasury {
+        treasury: Bech32Address,
+    },
+    FundRewards {
+        token_id: String,
+        amount: RustBigUint,
+    },
+    RewardConfig {
+        reward_rate: u64,
+        principal_only: bool,
+    },
+    PartialPongRemainder {
+        relock: bool,
+    },
+    PingFee {
+        user: Bech32Address,
+        deposit_id: u64,
+        token_id: String,
+        fee: RustBigUint,
+    },
+    ClaimFees {
+        fee_collector: Bech32Address,
+        token_id: String,
+        amount: RustBigUint,
+    },
+    PingFeeConfig {
+        flat: bool,
+        fee: RustBigUint,
+    },
+    FeeCollector {
+        fee_collector: Bech32Address,
+    },
+    LockTier {
+        tier_id: u64,
+        duration_in_seconds: u64,
+        reward_multiplier: u64,
+    },
+    RemoveLockTier {
+        tier_id: u64,
+    },
+    AutoRenewGracePeriod {
+        grace_period: u64,
+    },
+    ConfigTimelock {
+        delay_in_seconds: u64,
+        effective_timestamp: u64,
+    },
+    ConfigProposal {
+        proposal_id: u64,
+        ping_amount: RustBigUint,
+        duration_in_seconds: u64,
+        executable_timestamp: u64,
+    },
+    CancelConfigProposal {
+        proposal_id: u64,
+    },
+    ExecuteConfigProposal {
+        proposal_id: u64,
+    },
+    ReceiptToken {
+        token_id: String,
+    },
+    ReceiptTransferable {
+        transferable: bool,
+    },
+    Emergency {
+        timestamp: u64,
+    },
+    RoleGranted {
+        role: u64,
+        address: Bech32Address,
+    },
+    RoleRevoked {
+        role: u64,
+        address: Bech32Address,
+    },
+    Pause,
+    Unpause,
+    PausePong,
+    UnpausePong,
+}
+
+impl PingPongEvent {
+    /// Decodes a single log entry, returns `None` for logs not emitted by the contract itself
+    pub fn from_log(log: &Log) -> Option<Self> {
+        let (identifier, topics) = log.topics.split_first()?;
+
+        let event = match identifier.as_slice() {
+            b"pingEvent" => PingPongEvent::Ping {
+                user: decode_address(&topics[0]),
+                deposit_id: decode_u64(&topics[1]),
+                token_id: decode_token_id(&topics[2]),
+                amount: RustBigUint::from_bytes_be(&topics[3]),
+                ping_timestamp: decode_u64(&topics[4]),
+                unlock_timestamp: decode_u64(&topics[5]),
+            },
+            b"pongEvent" => PingPongEvent::Pong {
+                user: decode_address(&topics[0]),
+                receiver: decode_address(&topics[1]),
+                deposit_id: decode_u64(&topics[2]),
+                token_id: decode_token_id(&topics[3]),
+                amount: RustBigUint::from_bytes_be(&topics[4]),
+            },
+            b"pingForEvent" => PingPongEvent::PingFor {
+                funder: decode_address(&topics[0]),
+                beneficiary: decode_address(&topics[1]),
+                deposit_id: decode_u64(&topics[2]),
+            },
+            b"earlyPongEvent" => PingPongEvent::EarlyPong {
+                user: decode_address(&topics[0]),
+                deposit_id: decode_u64(&topics[1]),
+                token_id: decode_token_id(&topics[2]),
+                penalty: RustBigUint::from_bytes_be(&topics[3]),
+            },
+            b"partialPongEvent" => PingPongEvent::PartialPong {
+                user: decode_address(&topics[0]),
+                deposit_id: decode_u64(&topics[1]),
+                remaining_amount: RustBigUint::from_bytes_be(&topics[2]),
+                unlock_timestamp: decode_u64(&topics[3]),
+            },
+            b"lockExtendedEvent" => PingPongEvent::LockExtended {
+                user: decode_address(&topics[0]),
+                deposit_id: decode_u64(&topics[1]),
+                unlock_timestamp: decode_u64(&topics[2]),
+            },
+            b"autoRenewEvent" => PingPongEvent::AutoRenew {
+                user: decode_address(&topics[0]),
+                deposit_id: decode_u64(&topics[1]),
+                enabled: bool::top_decode(topics[2].as_slice()).unwrap(),
+            },
+            b"rewardEvent" => PingPongEvent::Reward {
+                user: decode_address(&topics[0]),
+                deposit_id: decode_u64(&topics[1]),
+                token_id: decode_token_id(&topics[2]),
+                reward: RustBigUint::from_bytes_be(&topics[3]),
+            },
+            b"configEvent" => PingPongEvent::Config {
+                token_id: decode_token_id(&topics[0]),
+                ping_amount: RustBigUint::from_bytes_be(&topics[1]),
+                duration_in_seconds: decode_u64(&topics[2]),
+            },
+            b"setAcceptedTokenEvent" => PingPongEvent::SetAcceptedToken {
+                token_id: decode_token_id(&topics[0]),
+                ping_amount: RustBigUint::from_bytes_be(&topics[1]),
+                max_ping_amount: RustBigUint::from_bytes_be(&topics[2]),
+            },
+            b"acceptedTokenNonceEvent" => PingPongEvent::AcceptedTokenNonce {
+                token_id: decode_token_id(&topics[0]),
+                nonce: decode_u64(&topics[1]),
+            },
+            b"removeAcceptedTokenEvent" => PingPongEvent::RemoveAcceptedToken {
+                token_id: decode_token_id(&topics[0]),
+            },
+            b"activationWindowEvent" => PingPongEvent::ActivationWindow {
+                ping_start_timestamp: decode_u64(&topics[0]),
+                ping_end_timestamp: decode_u64(&topics[1]),
+            },
+            b"multipleDepositsEvent" => PingPongEvent::MultipleDeposits {
+                enabled: bool::top_decode(topics[0].as_slice()).unwrap(),
+            },
+            b"pingForPongerEvent" => PingPongEvent::PingForPonger {
+                funder_pongs: decode_u64(&topics[0]) == 1,
+            },
+            b"earlyPongPenaltyEvent" => PingPongEvent::EarlyPongPenalty {
+                linear: decode_u64(&topics[0]) == 1,
+                penalty_rate: decode_u64(&topics[1]),
+            },
+            b"treasuryEvent" => PingPongEvent::Treasury {
+                treasury: decode_address(&topics[0]),
+            },
+            b"fundRewardsEvent" => PingPongEvent::FundRewards {
+                token_id: decode_token_id(&topics[0]),
+                amount: RustBigUint::from_bytes_be(&topics[1]),
+            },
+            b"rewardConfigEvent" => PingPongEvent::RewardConfig {
+                reward_rate: decode_u64(&topics[0]),
+                principal_only: decode_u64(&topics[1]) == 1,
+            },
+            b"partialPongRemainderEvent" => PingPongEvent::PartialPongRemainder {
+                relock: decode_u64(&topics[0]) == 1,
+            },
+            b"pingFeeEvent" => PingPongEvent::PingFee {
+                user: decode_address(&topics[0]),
+                deposit_id: decode_u64(&topics[1]),
+                token_id: decode_token_id(&topics[2]),
+                fee: RustBigUint::from_bytes_be(&topics[3]),
+            },
+            b"claimFeesEvent" => PingPongEvent::ClaimFees {
+                fee_collector: decode_address(&topics[0]),
+                token_id: decode_token_id(&topics[1]),
+                amount: RustBigUint::from_bytes_be(&topics[2]),
+            },
+            b"pingFeeConfigEvent" => PingPongEvent::PingFeeConfig {
+                flat: decode_u64(&topics[0]) == 1,
+                fee: RustBigUint::from_bytes_be(&topics[1]),
+            },
+            b"feeCollectorEvent" => PingPongEvent::FeeCollector {
+                fee_collector: decode_address(&topics[0]),
+            },
+            b"lockTierEvent" => PingPongEvent::LockTier {
+                tier_id: decode_u64(&topics[0]),
+                duration_in_seconds: decode_u64(&topics[1]),
+                reward_multiplier: decode_u64(&topics[2]),
+            },
+            b"removeLockTierEvent" => PingPongEvent::RemoveLockTier {
+                tier_id: decode_u64(&topics[0]),
+            },
+            b"autoRenewGracePeriodEvent" => PingPongEvent::AutoRenewGracePeriod {
+                grace_period: decode_u64(&topics[0]),
+            },
+            b"configTimelockEvent" => PingPongEvent::ConfigTimelock {
+                delay_in_seconds: decode_u64(&topics[0]),
+                effective_timestamp: decode_u64(&topics[1]),
+            },
+            b"configProposalEvent" => PingPongEvent::ConfigProposal {
+                proposal_id: decode_u64(&topics[0]),
+                ping_amount: RustBigUint::from_bytes_be(&topics[1]),
+                duration_in_seconds: decode_u64(&topics[2]),
+                executable_timestamp: decode_u64(&topics[3]),
+            },
+            b"cancelConfigProposalEvent" => PingPongEvent::CancelConfigProposal {
+                proposal_id: decode_u64(&topics[0]),
+            },
+            b"executeConfigProposalEvent" => PingPongEvent::ExecuteConfigProposal {
+                proposal_id: decode_u64(&topics[0]),
+            },
+            b"receiptTokenEvent" => PingPongEvent::ReceiptToken {
+                token_id: decode_token_id(&topics[0]),
+            },
+            b"receiptTransferableEvent" => PingPongEvent::ReceiptTransferable {
+                transferable: bool::top_decode(topics[0].as_slice()).unwrap(),
+            },
+            b"emergencyEvent" => PingPongEvent::Emergency {
+                timestamp: decode_u64(&topics[0]),
+            },
+            b"roleGrantedEvent" => PingPongEvent::RoleGranted {
+                role: decode_u64(&topics[0]),
+                address: decode_address(&topics[1]),
+            },
+            b"roleRevokedEvent" => PingPongEvent::RoleRevoked {
+                role: decode_u64(&topics[0]),
+                address: decode_address(&topics[1]),
+            },
+            b"pauseEvent" => PingPongEvent::Pause,
+            b"unpauseEvent" => PingPongEvent::Unpause,
+            b"pausePongEvent" => PingPongEvent::PausePong,
+            b"unpausePongEvent" => PingPongEvent::UnpausePong,
+            _ => return None,
+        };
+
+        Some(event)
+    }
+
+    /// Decodes all contract events from the logs of a transaction, in order
+    pub fn from_logs(logs: &[Log]) -> Vec<Self> {
+        logs.iter().filter_map(Self::from_log).collect()
+    }
+}
+
+fn decode_address(topic: &[u8]) -> Bech32Address {
+    Address::from_slice(topic).into()
+}
+
+fn decode_token_id(topic: &[u8]) -> String {
+    String::from_utf8(topic.to_vec()).unwrap()
+}
+
+fn decode_u64(topic: &[u8]) -> u64 {
+    u64::top_decode(topic).unwrap()
+}
@@ -44,18 +44,27 @@ where
     Gas: TxGas<Env>,
 {
     /// Necessary configuration when deploying: 
-    /// `ping_amount` - the exact amount that needs to be sent when `ping`-ing.   
+    /// `ping_amount` - the exact amount of the default token that needs to be sent when `ping`-ing, or the minimum in range mode.   
     /// `duration_in_seconds` - how much time (in seconds) until `pong` can be called after the initial `ping` call   
-    /// `token_id` - Optional. The Token Identifier of the token that is going to be used. Default is "EGLD". 
+    /// `token_id` - Optional. The Token Identifier of the default token. Other tokens can be added later by the owner. Default is "EGLD".   
+    /// `activation_window` - Optional. The start and end timestamps between which `ping` is accepted, 0 meaning no bound. Default is no window.   
+    /// `max_ping_amount` - Optional. If set, any amount between `ping_amount` and `max_ping_amount` can be `ping`-ed. Default is exact mode.   
+    /// `treasury` - Optional. The address receiving the `earlyPong` penalties. Default is none, which disables `earlyPong`. 
     pub fn init<
         Arg0: ProxyArg<BigUint<Env::Api>>,
         Arg1: ProxyArg<u64>,
         Arg2: ProxyArg<OptionalValue<EgldOrEsdtTokenIdentifier<Env::Api>>>,
+        Arg3: ProxyArg<OptionalValue<MultiValue2<u64, u64>>>,
+        Arg4: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg5: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         ping_amount: Arg0,
         duration_in_seconds: Arg1,
         opt_token_id: Arg2,
+        opt_activation_window: Arg3,
+        opt_max_ping_amount: Arg4,
+        opt_treasury: Arg5,
     ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -63,6 +72,9 @@ where
             .argument(&ping_amount)
             .argument(&duration_in_seconds)
             .argument(&opt_token_id)
+            .argument(&opt_activation_window)
+            .argument(&opt_max_ping_amount)
+            .argument(&opt_treasury)
             .original_result()
     }
 }
@@ -76,19 +88,34 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// `ping_amount`, `duration_in_seconds` - Only set on the upgrade from the first version. Afterwards they must be the current ones, 
+    /// as they can only be changed through config proposals.   
+    /// `activation_window` - Optional. If missing, the current window is kept.   
+    /// `max_ping_amount` - Optional. If missing, the current one is kept. Zero switches back to exact mode. 
+    /// It is not timelocked: it only changes the amounts new pings may send above `ping_amount`, and leaves open deposits as they are.   
+    /// `treasury` - Optional. If missing, the current one is kept. 
     pub fn upgrade<
         Arg0: ProxyArg<BigUint<Env::Api>>,
         Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<OptionalValue<MultiValue2<u64, u64>>>,
+        Arg3: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg4: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         ping_amount: Arg0,
         duration_in_seconds: Arg1,
+        opt_activation_window: Arg2,
+        opt_max_ping_amount: Arg3,
+        opt_treasury: Arg4,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
             .argument(&ping_amount)
             .argument(&duration_in_seconds)
+            .argument(&opt_activation_window)
+            .argument(&opt_max_ping_amount)
+            .argument(&opt_treasury)
             .original_result()
     }
 }
@@ -102,23 +129,218 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Applies a proposal made with `proposeConfig`, once its timelock has passed. 
+    /// The max ping amount of the default token is kept, so the new ping amount must stay below it in range mode. 
+    pub fn execute_config_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeConfigProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Allows users to hold several deposits at once, each with its own id and unlock time. 
+    /// Disabling it only stops new `ping`s from users that already hold a deposit. 
+    pub fn set_multiple_deposits<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMultipleDeposits")
+            .argument(&enabled)
+            .original_result()
+    }
+
     /// User sends some tokens to be locked in the contract for a period of time. 
-    pub fn ping(
+    /// `tier_id` - Optional. The lock tier, setting the lock duration and reward multiplier. Default is tier 0, which locks for `duration_in_seconds`. 
+    pub fn ping<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
         self,
+        opt_tier_id: Arg0,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("ping")
+            .argument(&opt_tier_id)
+            .original_result()
+    }
+
+    /// Locks the payment under `beneficiary` instead of the caller, recording the caller as the funder. 
+    /// Which of the two can `pong` it is decided by the owner with `setPingForPonger`, and fixed for the deposit at the time of the call. 
+    /// Such a deposit does not use up the single deposit of the beneficiary: they can still `ping` for themselves. 
+    /// `tier_id` - Optional. The lock tier, as for `ping`. 
+    /// Returns the id of the new deposit. 
+    pub fn ping_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        beneficiary: Arg0,
+        opt_tier_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("pingFor")
+            .argument(&beneficiary)
+            .argument(&opt_tier_id)
             .original_result()
     }
 
     /// User can take back funds from the contract. 
     /// Can only be called after expiration. 
-    pub fn pong(
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit. 
+    /// The receipt of the deposit, if it has one, must be sent along. 
+    pub fn pong<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        opt_deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
             .raw_call("pong")
+            .argument(&opt_deposit_id)
+            .original_result()
+    }
+
+    /// Same as `pong`, but the refund is sent to `destination` instead of the caller. 
+    pub fn pong_to<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        destination: Arg0,
+        opt_deposit_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("pongTo")
+            .argument(&destination)
+            .argument(&opt_deposit_id)
+            .original_result()
+    }
+
+    /// Takes back a deposit before its deadline, minus the penalty, which is sent to the treasury. 
+    /// Once the deposit is unlocked there is no penalty, same as `pong`. 
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit. 
+    pub fn early_pong<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        opt_deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("earlyPong")
+            .argument(&opt_deposit_id)
+            .original_result()
+    }
+
+    /// Takes back `amount` out of an unlocked deposit. The rest of it keeps its unlock time or is locked again, as configured by the owner. 
+    /// The reward accrued by the whole deposit is paid out. A new receipt, for what is left, replaces the one sent along. 
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit. 
+    pub fn pong_partial<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        amount: Arg0,
+        opt_deposit_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("pongPartial")
+            .argument(&amount)
+            .argument(&opt_deposit_id)
+            .original_result()
+    }
+
+    /// Pushes back the unlock time of a deposit by `extra_seconds`, counted from now if the deposit is already unlocked. 
+    /// The reward accrues over the extra seconds too, but not over the time the deposit sat unlocked. 
+    /// The receipt of the deposit, if it has one, must be sent along, and is replaced by one showing the new unlock time. 
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit. 
+    pub fn extend_lock<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        extra_seconds: Arg0,
+        opt_deposit_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("extendLock")
+            .argument(&extra_seconds)
+            .argument(&opt_deposit_id)
+            .original_result()
+    }
+
+    /// Opts a deposit in or out of auto-renew, keeping the current grace period for it. 
+    /// A deposit that opts in while already unlocked starts its grace period now, without reward for the time it sat unlocked. 
+    /// Opting out keeps the unlock time reached by the past renewals. 
+    /// The receipt of the deposit, if it has one, must be sent along, and is replaced by a new one. 
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit. 
+    pub fn set_auto_renew<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        enabled: Arg0,
+        opt_deposit_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("setAutoRenew")
+            .argument(&enabled)
+            .argument(&opt_deposit_id)
+            .original_result()
+    }
+
+    /// Takes back every deposit of the user that can already be `pong`-ed. 
+    /// Deposits that only their funder can `pong` are skipped, and so are deposits whose receipt was not sent along. 
+    /// The deposits of the receipts sent along are `pong`-ed too, whoever made them. 
+    pub fn pong_all(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("pongAll")
+            .original_result()
+    }
+
+    /// Refunds the matured deposits to whoever can `pong` them, so that positions left behind can be wound down. Anyone can call it. 
+    /// Deposits are walked by id, starting after the one the previous call stopped at, and starting over once past the last one. 
+    /// At most `max_count` deposits are looked at per call, to keep within the gas limit. 
+    /// Deposits with a receipt are left for the receipt holder to `pong`, and deposits owed to a smart contract, 
+    /// which might not accept the transfer and fail the whole call, for the contract to `pong` itself. 
+    /// Deposits whose reward the pool cannot cover are skipped too, when the empty pool policy is to fail, so that they keep their reward. 
+    /// Returns the number of deposits refunded. 
+    pub fn pong_matured<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pongMatured")
+            .argument(&max_count)
+            .original_result()
+    }
+
+    /// Refunds every deposit right away, whatever its deadline, to whoever can `pong` it. Only available in emergency mode, to anyone. 
+    /// Works in chunks of at most `max_count` deposits, like `pongMatured`, and skips the same deposits. 
+    /// Returns the number of deposits refunded. 
+    pub fn emergency_refund<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyRefund")
+            .argument(&max_count)
             .original_result()
     }
 
@@ -135,6 +357,62 @@ where
             .original_result()
     }
 
+    /// When the user holds several deposits, this and the other per-user views describe the one that unlocks first. 
+    pub fn get_user_deposit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DepositInfo<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserDeposit")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Lists all deposits of the user, as (deposit id, deposit). 
+    pub fn get_user_deposits<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, DepositInfo<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserDeposits")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DepositInfo<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeposit")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    pub fn get_user_ping_timestamp<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserPingTimestamp")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// The timestamp from which the user can `pong`, as fixed when they `ping`-ed. 
+    /// Later changes to `duration_in_seconds` do not move it. 
     pub fn get_pong_enable_timestamp<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -161,21 +439,120 @@ where
             .original_result()
     }
 
-    pub fn accepted_payment_token_id(
+    /// The penalty the user would pay to `earlyPong` their next deposit to unlock right now. 
+    pub fn get_early_pong_penalty<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAcceptedPaymentToken")
+            .raw_call("getEarlyPongPenalty")
+            .argument(&address)
             .original_result()
     }
 
-    pub fn ping_amount(
+    pub fn get_deposit_early_pong_penalty<
+        Arg0: ProxyArg<u64>,
+    >(
         self,
+        deposit_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPingAmount")
+            .raw_call("getDepositEarlyPongPenalty")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    /// The reward accrued so far by the user's next deposit to unlock, paid on `pong` if the pool allows it. 
+    pub fn get_pending_reward<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingReward")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_deposit_pending_reward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositPendingReward")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    /// Lists the lock tiers that can be picked on `ping`, as (tier id, duration in seconds, reward multiplier), the default tier first. 
+    pub fn get_lock_tiers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<u64, u64, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLockTiers")
+            .original_result()
+    }
+
+    pub fn get_deposit_lock_tier<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<LockTier>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositLockTier")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    /// The start and end timestamps between which `ping` is accepted, 0 meaning no bound. 
+    /// `pong` is never restricted by this window. 
+    pub fn get_activation_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActivationWindow")
+            .original_result()
+    }
+
+    pub fn get_active_user_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveUserCount")
+            .original_result()
+    }
+
+    /// Lists the users that currently have funds locked, as (address, amount, unlock timestamp) of their next deposit to unlock. 
+    /// `from` is a zero-based offset, `size` is the maximum number of entries returned. 
+    /// The order is not stable across `pong` calls, since removals swap the last user into the freed slot. 
+    /// A user listed without any deposit is skipped. 
+    pub fn get_active_users<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, BigUint<Env::Api>, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveUsers")
+            .argument(&from)
+            .argument(&size)
             .original_result()
     }
 
@@ -188,16 +565,884 @@ where
             .original_result()
     }
 
-    pub fn user_ping_timestamp<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    pub fn multiple_deposits_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMultipleDepositsEnabled")
+            .original_result()
+    }
+
+    /// The last deposit looked at by `pongMatured`, the next call resuming after it. 
+    pub fn pong_matured_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPongMaturedCursor")
+            .original_result()
+    }
+
+    /// The nonce of the NFT, SFT or meta-ESDT locked in a deposit, 0 for fungible tokens. 
+    pub fn deposit_token_nonce<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        address: Arg0,
+        deposit_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUserPingTimestamp")
-            .argument(&address)
+            .raw_call("getDepositTokenNonce")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    pub fn deposit_owner<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositOwner")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    /// Adds a token to the whitelist, or updates its ping amount if it is already accepted. 
+    /// The ping amount of the default token can only be changed through config proposals. 
+    /// `max_ping_amount` - Optional. If set, any amount between `ping_amount` and `max_ping_amount` can be `ping`-ed. Default is exact mode. 
+    pub fn add_accepted_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        token_id: Arg0,
+        ping_amount: Arg1,
+        opt_max_ping_amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addAcceptedToken")
+            .argument(&token_id)
+            .argument(&ping_amount)
+            .argument(&opt_max_ping_amount)
+            .original_result()
+    }
+
+    /// The default token, set at deploy, cannot be removed. 
+    pub fn remove_accepted_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAcceptedToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Restricts the `ping`s with an NFT, SFT or meta-ESDT collection to a single nonce. Zero accepts any nonce. 
+    pub fn set_accepted_token_nonce<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAcceptedTokenNonce")
+            .argument(&token_id)
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// The ping amount of the default token, which is the minimum in range mode. 
+    pub fn get_ping_amount(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPingAmount")
+            .original_result()
+    }
+
+    /// The max ping amount of the default token, zero in exact mode. 
+    pub fn get_max_ping_amount(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxPingAmount")
             .original_result()
     }
+
+    /// Lists every accepted token as (token, ping amount, max ping amount). 
+    pub fn get_accepted_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAcceptedTokens")
+            .original_result()
+    }
+
+    pub fn accepted_payment_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAcceptedPaymentToken")
+            .original_result()
+    }
+
+    pub fn token_ping_amount<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenPingAmount")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn token_max_ping_amount<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenMaxPingAmount")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// The only nonce accepted for a token, 0 meaning any nonce. 
+    pub fn token_nonce<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAcceptedTokenNonce")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Zero disables auto-renew. The deposits that already opted in keep the grace period they opted in with. 
+    pub fn set_auto_renew_grace_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        grace_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAutoRenewGracePeriod")
+            .argument(&grace_period)
+            .original_result()
+    }
+
+    pub fn auto_renew_grace_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAutoRenewGracePeriod")
+            .original_result()
+    }
+
+    pub fn deposit_auto_renew<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAutoRenewEnabled")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    /// Only applies to proposals made from now on. 
+    /// An increase takes effect right away. A decrease only takes effect once the current timelock has passed, 
+    /// so that it cannot be used to rush a proposal through. 
+    pub fn set_config_timelock<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        delay_in_seconds: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setConfigTimelock")
+            .argument(&delay_in_seconds)
+            .original_result()
+    }
+
+    /// Returns the id of the proposal, needed to execute or cancel it. 
+    pub fn propose_config<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        ping_amount: Arg0,
+        duration_in_seconds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeConfig")
+            .argument(&ping_amount)
+            .argument(&duration_in_seconds)
+            .original_result()
+    }
+
+    pub fn cancel_config_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelConfigProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// How long, in seconds, a proposal made now waits before it can be executed. 
+    pub fn get_config_timelock(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getConfigTimelock")
+            .original_result()
+    }
+
+    /// The decrease of the timelock that has not taken effect yet, if any. 
+    pub fn get_pending_config_timelock(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ConfigTimelockChange>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingConfigTimelock")
+            .original_result()
+    }
+
+    /// Lists every pending proposal as (proposal id, proposal). 
+    pub fn get_pending_config_proposals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, ConfigProposal<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingConfigProposals")
+            .original_result()
+    }
+
+    pub fn set_early_pong_penalty<
+        Arg0: ProxyArg<PenaltyType>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        penalty_type: Arg0,
+        penalty_rate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEarlyPongPenalty")
+            .argument(&penalty_type)
+            .argument(&penalty_rate)
+            .original_result()
+    }
+
+    pub fn get_early_pong_penalty_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<PenaltyType, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEarlyPongPenaltyConfig")
+            .original_result()
+    }
+
+    /// Empty when no treasury was set, in which case `earlyPong` is disabled. 
+    pub fn get_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasury")
+            .original_result()
+    }
+
+    pub fn enter_emergency(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enterEmergency")
+            .original_result()
+    }
+
+    /// Empty while not in emergency mode. 
+    pub fn get_emergency_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEmergencyTimestamp")
+            .original_result()
+    }
+
+    pub fn emergency(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isEmergency")
+            .original_result()
+    }
+
+    /// The last deposit looked at by `emergencyRefund`, the next call resuming after it. 
+    pub fn emergency_refund_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEmergencyRefundCursor")
+            .original_result()
+    }
+
+    /// Adds a lock tier, or updates it if it already exists. 
+    /// `reward_multiplier` - Optional. Applied to the reward rate, in basis points. Default is 10 000, which is 1x. At most 100 000, which is 10x. 
+    pub fn set_lock_tier<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        tier_id: Arg0,
+        duration_in_seconds: Arg1,
+        opt_reward_multiplier: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLockTier")
+            .argument(&tier_id)
+            .argument(&duration_in_seconds)
+            .argument(&opt_reward_multiplier)
+            .original_result()
+    }
+
+    /// Stops new `ping`s with a tier. Open positions keep their lock duration and multiplier. 
+    pub fn remove_lock_tier<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tier_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeLockTier")
+            .argument(&tier_id)
+            .original_result()
+    }
+
+    pub fn set_partial_pong_remainder<
+        Arg0: ProxyArg<PartialPongRemainder>,
+    >(
+        self,
+        remainder: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPartialPongRemainder")
+            .argument(&remainder)
+            .original_result()
+    }
+
+    pub fn partial_pong_remainder(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PartialPongRemainder> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartialPongRemainder")
+            .original_result()
+    }
+
+    /// Stops `ping` until `unpause` is called. Does not affect `pong`. 
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    /// Stops `pong` until `unpausePong` is called. Does not affect `ping`. 
+    pub fn pause_pong(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pausePong")
+            .original_result()
+    }
+
+    pub fn unpause_pong(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpausePong")
+            .original_result()
+    }
+
+    pub fn ping_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn pong_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPongPaused")
+            .original_result()
+    }
+
+    /// A fee of zero disables it. A flat fee is an amount of the default token, so it is only charged on `ping`s in it. 
+    pub fn set_ping_fee<
+        Arg0: ProxyArg<PingFeeType>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        fee_type: Arg0,
+        fee: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPingFee")
+            .argument(&fee_type)
+            .argument(&fee)
+            .original_result()
+    }
+
+    pub fn set_fee_collector<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        fee_collector: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFeeCollector")
+            .argument(&fee_collector)
+            .original_result()
+    }
+
+    /// Sends all the fees collected so far, in every token, to the fee collector. 
+    pub fn claim_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimFees")
+            .original_result()
+    }
+
+    pub fn get_ping_fee_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<PingFeeType, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPingFeeConfig")
+            .original_result()
+    }
+
+    pub fn get_fee_collector(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeCollector")
+            .original_result()
+    }
+
+    /// Lists the fees not claimed yet, as (token, amount). 
+    pub fn get_accumulated_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatedFees")
+            .original_result()
+    }
+
+    /// Only applies to `pingFor` deposits made from now on. 
+    pub fn set_ping_for_ponger<
+        Arg0: ProxyArg<PingForPonger>,
+    >(
+        self,
+        ponger: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPingForPonger")
+            .argument(&ponger)
+            .original_result()
+    }
+
+    /// The funder of a `pingFor` deposit, empty for deposits funded by their owner. 
+    pub fn get_deposit_funder<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositFunder")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    pub fn ping_for_ponger(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PingForPonger> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPingForPonger")
+            .original_result()
+    }
+
+    /// Issues the receipt NFT collection, the EGLD sent paying the issue cost. 
+    /// No receipt is minted until `setReceiptTransferable` is called: `false` gives the contract the ESDT transfer role, 
+    /// which restricts receipts to transfers back to the contract, `true` lets them be traded like any NFT. 
+    pub fn issue_receipt_token<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueReceiptToken")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .original_result()
+    }
+
+    /// Restricts transfers of the receipts, through the ESDT transfer role, so that they can only be sent back to the contract, or lifts the restriction. 
+    pub fn set_receipt_transferable<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        transferable: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReceiptTransferable")
+            .argument(&transferable)
+            .original_result()
+    }
+
+    pub fn receipt_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReceiptTokenId")
+            .original_result()
+    }
+
+    pub fn receipt_transferable(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isReceiptTransferable")
+            .original_result()
+    }
+
+    /// The nonce of the receipt minted for a deposit, 0 if it has none. 
+    pub fn deposit_receipt_nonce<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositReceiptNonce")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    /// The deposit a receipt was minted for, 0 once it was `pong`-ed. 
+    pub fn receipt_deposit_id<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        receipt_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReceiptDepositId")
+            .argument(&receipt_nonce)
+            .original_result()
+    }
+
+    pub fn fund_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundRewards")
+            .original_result()
+    }
+
+    /// Only applies to deposits made from now on. 
+    pub fn set_reward_config<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EmptyPoolPolicy>,
+    >(
+        self,
+        reward_rate: Arg0,
+        empty_pool_policy: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRewardConfig")
+            .argument(&reward_rate)
+            .argument(&empty_pool_policy)
+            .original_result()
+    }
+
+    pub fn get_reward_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, EmptyPoolPolicy>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardConfig")
+            .original_result()
+    }
+
+    pub fn reward_pool<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardPool")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    /// The owner is not listed, even though it can do everything a role allows. 
+    pub fn has_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_role_members<
+        Arg0: ProxyArg<Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleMembers")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn get_stats(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Stats<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStats")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DepositInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+    pub ping_timestamp: u64,
+    pub unlock_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct LockTier {
+    pub duration_in_seconds: u64,
+    pub reward_multiplier: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ConfigTimelockChange {
+    pub delay_in_seconds: u64,
+    pub effective_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ConfigProposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ping_amount: BigUint<Api>,
+    pub duration_in_seconds: u64,
+    pub executable_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PenaltyType {
+    Flat,
+    Linear,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PartialPongRemainder {
+    KeepUnlocked,
+    Relock,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PingFeeType {
+    Rate,
+    Flat,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PingForPonger {
+    Beneficiary,
+    Funder,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EmptyPoolPolicy {
+    Fail,
+    PrincipalOnly,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Pauser,
+    ConfigManager,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Stats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ping_count: u64,
+    pub pong_count: u64,
+    pub tokens: ManagedVec<Api, TokenStats<Api>>,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug,
+)]
+pub struct TokenStats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub total_locked: BigUint<Api>,
+    pub total_pinged: BigUint<Api>,
+    pub total_ponged: BigUint<Api>,
 }
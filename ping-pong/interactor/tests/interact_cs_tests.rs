@@ -1,5 +1,5 @@
 use multiversx_sc_snippets::imports::*;
-use ping_pong_interact::{Config, PingPongInteract, EGLD};
+use ping_pong_interact::{Config, PingPongEvent, PingPongInteract, Role, EGLD};
 
 #[tokio::test]
 #[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
@@ -11,7 +11,9 @@ async fn test_ping_pong_cs() {
     let amount = RustBigUint::from(1u32);
     let time = 15u64;
 
-    interactor.deploy(amount, time, EGLD.to_string()).await;
+    interactor
+        .deploy(amount, time, EGLD.to_string(), None, None, None)
+        .await;
 
     interactor
         .ping(
@@ -19,24 +21,336 @@ async fn test_ping_pong_cs() {
             0,
             2u64,
             &alice,
+            None,
             Some("The payment must match the fixed ping amount"),
         )
         .await;
     interactor
-        .ping(EGLD.to_string(), 0, 1u64, &alice, None)
+        .ping(EGLD.to_string(), 0, 1u64, &alice, None, None)
         .await;
     assert!(interactor.did_user_ping(alice.clone()).await);
 
     assert!(!interactor.did_user_ping(mike.clone()).await);
     interactor
-        .ping(EGLD.to_string(), 0, 1u64, &mike, None)
+        .ping(EGLD.to_string(), 0, 1u64, &mike, None, None)
         .await;
 
     assert_eq!(Some(15), interactor.get_time_to_pong(mike.clone()).await);
+    assert_eq!(2, interactor.active_user_count().await);
+    let active_users = interactor.active_users(1, 10).await;
+    assert_eq!(1, active_users.len());
+    assert_eq!(mike, active_users[0].0);
     assert_eq!(EGLD, interactor.accepted_payment_token_id().await);
     assert_eq!(RustBigUint::from(1u64), interactor.ping_amount().await);
+    assert_eq!(RustBigUint::from(0u64), interactor.max_ping_amount().await);
+    assert_eq!(
+        vec![(
+            EGLD.to_string(),
+            RustBigUint::from(1u64),
+            RustBigUint::from(0u64)
+        )],
+        interactor.accepted_tokens().await
+    );
+    interactor
+        .remove_accepted_token(EGLD.to_string(), Some("Cannot remove the default token"))
+        .await;
+    interactor
+        .set_accepted_token_nonce(EGLD.to_string(), 1, Some("EGLD has no nonce"))
+        .await;
+    assert_eq!(0, interactor.accepted_token_nonce(EGLD.to_string()).await);
     assert_eq!(time, interactor.duration_in_seconds().await);
+    assert_eq!((0, 0), interactor.activation_window().await);
 
-    interactor.pong(&alice, None).await;
-    interactor.pong(&alice, Some("Must ping first")).await;
+    let events = interactor.pong(&alice, None, &[], None).await;
+    assert_eq!(
+        events,
+        vec![PingPongEvent::Pong {
+            user: alice.clone(),
+            receiver: alice.clone(),
+            deposit_id: 1,
+            token_id: EGLD.to_string(),
+            amount: RustBigUint::from(1u64),
+        }]
+    );
+    interactor
+        .pong(&alice, None, &[], Some("Must ping first"))
+        .await;
+
+    interactor
+        .pong_matured(&mike, 0, Some("Max count must be greater than zero"))
+        .await;
+    assert_eq!(0, interactor.pong_matured_cursor().await);
+
+    interactor
+        .propose_config(
+            RustBigUint::from(2u64),
+            0,
+            Some("Duration in seconds cannot be set to zero"),
+        )
+        .await;
+    interactor.set_config_timelock(3_600, None).await;
+    assert_eq!(3_600, interactor.config_timelock().await);
+    let events = interactor
+        .propose_config(RustBigUint::from(2u64), 30, None)
+        .await;
+    let Some(PingPongEvent::ConfigProposal { proposal_id, .. }) = events.last().cloned() else {
+        panic!("missing config proposal event");
+    };
+    let pending = interactor.pending_config_proposals().await;
+    assert_eq!(1, pending.len());
+    assert_eq!(
+        (proposal_id, RustBigUint::from(2u64), 30),
+        (pending[0].0, pending[0].1.clone(), pending[0].2)
+    );
+    interactor
+        .execute_config_proposal(proposal_id, Some("Config proposal is still timelocked"))
+        .await;
+    interactor.cancel_config_proposal(proposal_id, None).await;
+    assert!(interactor.pending_config_proposals().await.is_empty());
+    interactor
+        .execute_config_proposal(proposal_id, Some("Config proposal not found"))
+        .await;
+    interactor.set_config_timelock(0, None).await;
+    assert_eq!(3_600, interactor.config_timelock().await);
+    let pending_timelock = interactor.pending_config_timelock().await;
+    assert_eq!(Some(0), pending_timelock.map(|change| change.delay_in_seconds));
+    interactor
+        .add_accepted_token(
+            EGLD.to_string(),
+            RustBigUint::from(2u64),
+            None,
+            Some("Cannot change the default token"),
+        )
+        .await;
+
+    let stats = interactor.stats().await;
+    assert_eq!(2, stats.ping_count);
+    assert_eq!(1, stats.pong_count);
+
+    let events = interactor
+        .grant_role(Role::Pauser, mike.clone(), None)
+        .await;
+    assert_eq!(
+        events,
+        vec![PingPongEvent::RoleGranted {
+            role: 0,
+            address: mike.clone(),
+        }]
+    );
+    interactor
+        .grant_role(
+            Role::Pauser,
+            mike.clone(),
+            Some("Address already has the role"),
+        )
+        .await;
+    assert!(interactor.has_role(Role::Pauser, mike.clone()).await);
+    assert!(!interactor.has_role(Role::ConfigManager, mike.clone()).await);
+    assert_eq!(
+        vec![mike.clone()],
+        interactor.role_members(Role::Pauser).await
+    );
+    interactor
+        .revoke_role(Role::Pauser, mike.clone(), None)
+        .await;
+    assert!(interactor.role_members(Role::Pauser).await.is_empty());
+
+    interactor.pause(None).await;
+    assert!(interactor.is_paused().await);
+    interactor
+        .ping(
+            EGLD.to_string(),
+            0,
+            1u64,
+            &alice,
+            None,
+            Some("Ping is paused"),
+        )
+        .await;
+    interactor.unpause(None).await;
+    assert!(!interactor.is_paused().await);
+
+    interactor
+        .ping(
+            EGLD.to_string(),
+            0,
+            1u64,
+            &mike,
+            None,
+            Some("Already pinged"),
+        )
+        .await;
+    interactor.set_multiple_deposits(true, None).await;
+    assert!(interactor.is_multiple_deposits_enabled().await);
+    interactor
+        .ping(EGLD.to_string(), 0, 1u64, &mike, None, None)
+        .await;
+    interactor
+        .set_lock_tier(0, 30, None, Some("Cannot change the default tier"))
+        .await;
+    interactor.set_lock_tier(1, 30, Some(15_000), None).await;
+    assert_eq!(
+        vec![(0, time, 10_000), (1, 30, 15_000)],
+        interactor.lock_tiers().await
+    );
+    interactor
+        .ping(
+            EGLD.to_string(),
+            0,
+            1u64,
+            &mike,
+            Some(2),
+            Some("Lock tier not found"),
+        )
+        .await;
+    let deposits = interactor.user_deposits(mike.clone()).await;
+    let lock_tier = interactor.deposit_lock_tier(deposits[1].0).await.unwrap();
+    assert_eq!(time, lock_tier.duration_in_seconds);
+    assert_eq!(2, deposits.len());
+    assert!(interactor.deposit(deposits[1].0).await.is_some());
+    interactor
+        .pong(&mike, None, &[], Some("Deposit id required"))
+        .await;
+
+    let ping_pong_address = interactor.state.current_ping_pong_address().clone();
+    interactor
+        .ping_for(
+            EGLD.to_string(),
+            0,
+            1u64,
+            &alice,
+            ping_pong_address.clone(),
+            None,
+            Some("Cannot ping for the contract itself"),
+        )
+        .await;
+    let events = interactor
+        .ping_for(EGLD.to_string(), 0, 1u64, &alice, mike.clone(), None, None)
+        .await;
+    let Some(PingPongEvent::PingFor {
+        funder, deposit_id, ..
+    }) = events.last().cloned()
+    else {
+        panic!("missing ping for event");
+    };
+    assert_eq!(alice, funder);
+    assert_eq!(mike, interactor.deposit_owner(deposit_id).await);
+    assert_eq!(
+        Some(alice.clone()),
+        interactor.deposit_funder(deposit_id).await
+    );
+    interactor
+        .pong(
+            &alice,
+            Some(deposit_id),
+            &[],
+            Some("Caller cannot pong this deposit"),
+        )
+        .await;
+    interactor
+        .pong_to(
+            &mike,
+            ping_pong_address,
+            Some(deposit_id),
+            &[],
+            Some("Cannot pong to the contract itself"),
+        )
+        .await;
+
+    interactor
+        .pong_partial(
+            &alice,
+            RustBigUint::from(1u64),
+            Some(deposit_id),
+            &[],
+            Some("Caller cannot pong this deposit"),
+        )
+        .await;
+
+    interactor
+        .set_auto_renew(
+            &mike,
+            true,
+            Some(deposit_id),
+            &[],
+            Some("Auto-renew is not enabled"),
+        )
+        .await;
+    interactor.set_auto_renew_grace_period(10, None).await;
+    assert_eq!(10, interactor.auto_renew_grace_period().await);
+    interactor
+        .set_auto_renew(&mike, true, Some(deposit_id), &[], None)
+        .await;
+    assert!(interactor.is_auto_renew_enabled(deposit_id).await);
+    interactor
+        .extend_lock(
+            &mike,
+            0,
+            Some(deposit_id),
+            &[],
+            Some("Extra seconds must be greater than zero"),
+        )
+        .await;
+
+    assert_eq!(None, interactor.fee_collector().await);
+    assert!(interactor.accumulated_fees().await.is_empty());
+    interactor.claim_fees(Some("Fee collector not set")).await;
+
+    assert_eq!(None, interactor.treasury().await);
+    interactor
+        .early_pong(
+            &mike,
+            Some(deposit_id),
+            &[],
+            Some("Early pong is not enabled"),
+        )
+        .await;
+
+    assert_eq!(0, interactor.reward_config().await.0);
+    let events = interactor
+        .fund_rewards(EGLD.to_string(), 0, 10u64, None)
+        .await;
+    assert_eq!(
+        events,
+        vec![PingPongEvent::FundRewards {
+            token_id: EGLD.to_string(),
+            amount: RustBigUint::from(10u64),
+        }]
+    );
+    assert_eq!(
+        RustBigUint::from(10u64),
+        interactor.reward_pool(EGLD.to_string()).await
+    );
+    assert_eq!(
+        RustBigUint::from(0u64),
+        interactor.pending_reward(alice.clone()).await
+    );
+
+    assert_eq!(0, interactor.deposit_token_nonce(deposit_id).await);
+    assert_eq!(0, interactor.deposit_receipt_nonce(deposit_id).await);
+    assert_eq!(0, interactor.receipt_deposit_id(1).await);
+    interactor
+        .set_receipt_transferable(false, Some("Must issue or set token ID first"))
+        .await;
+
+    assert!(!interactor.is_emergency().await);
+    assert_eq!(None, interactor.emergency_timestamp().await);
+    interactor
+        .emergency_refund(&mike, 1, Some("Not in emergency mode"))
+        .await;
+    interactor.enter_emergency(None).await;
+    assert!(interactor.emergency_timestamp().await.is_some());
+    interactor
+        .enter_emergency(Some("Already in emergency mode"))
+        .await;
+    interactor
+        .ping(
+            EGLD.to_string(),
+            0,
+            1u64,
+            &alice,
+            None,
+            Some("Emergency mode is active"),
+        )
+        .await;
 }
@@ -0,0 +1,185 @@
+use multiversx_sc::imports::*;
+
+/// Whitelist of the tokens that can be `ping`-ed, each with its own amount.
+/// A token either requires an exact amount, or, if it has a max ping amount, any amount in the `[ping_amount, max_ping_amount]` range.
+/// NFT, SFT and meta-ESDT collections can be accepted too, either with any nonce or restricted to a single one.
+/// Removing a token only stops new `ping`s with it, open positions are refunded from their own snapshot.
+#[multiversx_sc::module]
+pub trait AcceptedTokensModule: crate::roles::RolesModule {
+    /// Adds a token to the whitelist, or updates its ping amount if it is already accepted.
+    /// The ping amount of the default token can only be changed through config proposals.
+    /// `max_ping_amount` - Optional. If set, any amount between `ping_amount` and `max_ping_amount` can be `ping`-ed. Default is exact mode.
+    #[endpoint(addAcceptedToken)]
+    fn add_accepted_token(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        ping_amount: BigUint,
+        opt_max_ping_amount: OptionalValue<BigUint>,
+    ) {
+        self.require_config_manager();
+
+        require!(token_id.is_valid(), "Invalid token identifier");
+        require!(
+            token_id != self.accepted_payment_token_id().get(),
+            "Cannot change the default token"
+        );
+
+        let max_ping_amount = opt_max_ping_amount.into_option().unwrap_or_default();
+        self.set_token_ping_amount(&token_id, &ping_amount, &max_ping_amount);
+    }
+
+    /// The default token, set at deploy, cannot be removed.
+    #[endpoint(removeAcceptedToken)]
+    fn remove_accepted_token(&self, token_id: EgldOrEsdtTokenIdentifier) {
+        self.require_config_manager();
+
+        require!(
+            token_id != self.accepted_payment_token_id().get(),
+            "Cannot remove the default token"
+        );
+        require!(
+            self.accepted_tokens().swap_remove(&token_id),
+            "Token is not accepted"
+        );
+
+        self.token_ping_amount(&token_id).clear();
+        self.token_max_ping_amount(&token_id).clear();
+        self.token_nonce(&token_id).clear();
+        self.remove_accepted_token_event(&token_id);
+    }
+
+    /// Restricts the `ping`s with an NFT, SFT or meta-ESDT collection to a single nonce. Zero accepts any nonce.
+    #[endpoint(setAcceptedTokenNonce)]
+    fn set_accepted_token_nonce(&self, token_id: EgldOrEsdtTokenIdentifier, nonce: u64) {
+        self.require_config_manager();
+
+        require!(
+            self.accepted_tokens().contains(&token_id),
+            "Token is not accepted"
+        );
+        require!(nonce == 0 || token_id.is_esdt(), "EGLD has no nonce");
+
+        self.token_nonce(&token_id).set(nonce);
+        self.accepted_token_nonce_event(&token_id, nonce);
+    }
+
+    /// A `max_ping_amount` of zero means the exact `ping_amount` is required.
+    fn set_token_ping_amount(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        ping_amount: &BigUint,
+        max_ping_amount: &BigUint,
+    ) {
+        require!(*ping_amount > 0, "Ping amount cannot be set to zero");
+        require!(
+            *max_ping_amount == 0 || max_ping_amount > ping_amount,
+            "Max ping amount must be greater than the ping amount"
+        );
+
+        self.accepted_tokens().insert(token_id.clone());
+        self.token_ping_amount(token_id).set(ping_amount);
+        self.token_max_ping_amount(token_id).set(max_ping_amount);
+        self.set_accepted_token_event(token_id, ping_amount, max_ping_amount);
+    }
+
+    fn require_valid_ping_amount(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        let ping_amount = self.token_ping_amount(token_id).get();
+        let max_ping_amount = self.token_max_ping_amount(token_id).get();
+        if max_ping_amount == 0 {
+            require!(
+                *amount == ping_amount,
+                "The payment must match the fixed ping amount"
+            );
+        } else {
+            require!(
+                *amount >= ping_amount && *amount <= max_ping_amount,
+                "The payment must be within the ping amount range"
+            );
+        }
+    }
+
+    fn require_valid_ping_nonce(&self, token_id: &EgldOrEsdtTokenIdentifier, nonce: u64) {
+        let accepted_nonce = self.token_nonce(token_id).get();
+        require!(
+            accepted_nonce == 0 || nonce == accepted_nonce,
+            "Invalid payment nonce"
+        );
+    }
+
+    // views
+
+    /// The ping amount of the default token, which is the minimum in range mode.
+    #[view(getPingAmount)]
+    fn get_ping_amount(&self) -> BigUint {
+        let token_id = self.accepted_payment_token_id().get();
+        self.token_ping_amount(&token_id).get()
+    }
+
+    /// The max ping amount of the default token, zero in exact mode.
+    #[view(getMaxPingAmount)]
+    fn get_max_ping_amount(&self) -> BigUint {
+        let token_id = self.accepted_payment_token_id().get();
+        self.token_max_ping_amount(&token_id).get()
+    }
+
+    /// Lists every accepted token as (token, ping amount, max ping amount).
+    #[view(getAcceptedTokens)]
+    fn get_accepted_tokens(
+        &self,
+    ) -> MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token_id in self.accepted_tokens().iter() {
+            let ping_amount = self.token_ping_amount(&token_id).get();
+            let max_ping_amount = self.token_max_ping_amount(&token_id).get();
+            result.push((token_id, ping_amount, max_ping_amount).into());
+        }
+
+        result
+    }
+
+    // storage
+
+    #[view(getAcceptedPaymentToken)]
+    #[storage_mapper("acceptedPaymentTokenId")]
+    fn accepted_payment_token_id(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("acceptedTokens")]
+    fn accepted_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getTokenPingAmount)]
+    #[storage_mapper("tokenPingAmount")]
+    fn token_ping_amount(&self, token_id: &EgldOrEsdtTokenIdentifier)
+        -> SingleValueMapper<BigUint>;
+
+    #[view(getTokenMaxPingAmount)]
+    #[storage_mapper("tokenMaxPingAmount")]
+    fn token_max_ping_amount(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// The only nonce accepted for a token, 0 meaning any nonce.
+    #[view(getAcceptedTokenNonce)]
+    #[storage_mapper("tokenNonce")]
+    fn token_nonce(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u64>;
+
+    // events
+
+    #[event("setAcceptedTokenEvent")]
+    fn set_accepted_token_event(
+        &self,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] ping_amount: &BigUint,
+        #[indexed] max_ping_amount: &BigUint,
+    );
+
+    #[event("acceptedTokenNonceEvent")]
+    fn accepted_token_nonce_event(
+        &self,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] nonce: u64,
+    );
+
+    #[event("removeAcceptedTokenEvent")]
+    fn remove_accepted_token_event(&self, #[indexed] token_id: &EgldOrEsdtTokenIdentifier);
+}
@@ -0,0 +1,73 @@
+use multiversx_sc::imports::*;
+
+/// Lets users opt a deposit into auto-renew: once unlocked, it can be `pong`-ed during a grace period set by the owner,
+/// as it was when the deposit opted in.
+/// After that, it is locked again for the duration of its lock tier, and so on, saving the user a `pong` and a new `ping`.
+/// Renewals are not stored, they are applied whenever the deposit is read.
+#[multiversx_sc::module]
+pub trait AutoRenewModule: crate::roles::RolesModule {
+    /// Zero disables auto-renew. The deposits that already opted in keep the grace period they opted in with.
+    #[endpoint(setAutoRenewGracePeriod)]
+    fn set_auto_renew_grace_period(&self, grace_period: u64) {
+        self.require_config_manager();
+
+        self.auto_renew_grace_period().set(grace_period);
+        self.auto_renew_grace_period_event(grace_period);
+    }
+
+    /// The unlock timestamp of an auto-renewed deposit, given the unlock timestamp it was stored with.
+    fn get_renewed_unlock_timestamp(
+        &self,
+        unlock_timestamp: u64,
+        lock_duration: u64,
+        grace_period: u64,
+        current_timestamp: u64,
+    ) -> u64 {
+        if grace_period == 0 || current_timestamp < unlock_timestamp + grace_period {
+            return unlock_timestamp;
+        }
+
+        let renewal_period = grace_period + lock_duration;
+        let time_since_unlock = current_timestamp - unlock_timestamp;
+        let past_renewals = time_since_unlock / renewal_period;
+        if time_since_unlock % renewal_period < grace_period {
+            unlock_timestamp + past_renewals * renewal_period
+        } else {
+            unlock_timestamp + (past_renewals + 1) * renewal_period
+        }
+    }
+
+    // storage
+
+    #[view(getAutoRenewGracePeriod)]
+    #[storage_mapper("autoRenewGracePeriod")]
+    fn auto_renew_grace_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(isAutoRenewEnabled)]
+    #[storage_mapper("depositAutoRenew")]
+    fn deposit_auto_renew(&self, deposit_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("depositAutoRenewGracePeriod")]
+    fn deposit_auto_renew_grace_period(&self, deposit_id: u64) -> SingleValueMapper<u64>;
+
+    // events
+
+    #[event("autoRenewGracePeriodEvent")]
+    fn auto_renew_grace_period_event(&self, #[indexed] grace_period: u64);
+
+    #[event("autoRenewEvent")]
+    fn auto_renew_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] enabled: bool,
+    );
+
+    #[event("lockExtendedEvent")]
+    fn lock_extended_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] unlock_timestamp: u64,
+    );
+}
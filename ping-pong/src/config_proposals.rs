@@ -0,0 +1,201 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ConfigProposal<M: ManagedTypeApi> {
+    /// The new ping amount of the default token.
+    pub ping_amount: BigUint<M>,
+    pub duration_in_seconds: u64,
+    /// The proposal cannot be executed before this timestamp.
+    pub executable_timestamp: u64,
+}
+
+/// A decrease of the timelock, which only takes effect once the timelock it replaces has passed.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ConfigTimelockChange {
+    pub delay_in_seconds: u64,
+    pub effective_timestamp: u64,
+}
+
+/// Gives users time to react to a change of `ping_amount` or `duration_in_seconds`:
+/// a config manager proposes it, and it can only be executed once the timelock set by the owner has passed.
+/// Pending proposals are public and can be cancelled until they are executed.
+#[multiversx_sc::module]
+pub trait ConfigProposalsModule: crate::roles::RolesModule {
+    /// Only applies to proposals made from now on.
+    /// An increase takes effect right away. A decrease only takes effect once the current timelock has passed,
+    /// so that it cannot be used to rush a proposal through.
+    #[only_owner]
+    #[endpoint(setConfigTimelock)]
+    fn set_config_timelock(&self, delay_in_seconds: u64) {
+        self.apply_config_timelock_change();
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let config_timelock = self.config_timelock().get();
+        if delay_in_seconds >= config_timelock {
+            self.config_timelock().set(delay_in_seconds);
+            self.config_timelock_change().clear();
+            self.config_timelock_event(delay_in_seconds, current_timestamp);
+            return;
+        }
+
+        let effective_timestamp = current_timestamp + config_timelock;
+        self.config_timelock_change().set(ConfigTimelockChange {
+            delay_in_seconds,
+            effective_timestamp,
+        });
+        self.config_timelock_event(delay_in_seconds, effective_timestamp);
+    }
+
+    /// Returns the id of the proposal, needed to execute or cancel it.
+    #[endpoint(proposeConfig)]
+    fn propose_config(&self, ping_amount: BigUint, duration_in_seconds: u64) -> u64 {
+        self.require_config_manager();
+
+        require!(ping_amount > 0, "Ping amount cannot be set to zero");
+        require!(
+            duration_in_seconds > 0,
+            "Duration in seconds cannot be set to zero"
+        );
+
+        let proposal_id = self.last_config_proposal_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.apply_config_timelock_change();
+        let executable_timestamp =
+            self.blockchain().get_block_timestamp() + self.config_timelock().get();
+        self.config_proposal_ids().insert(proposal_id);
+        self.config_proposal(proposal_id).set(ConfigProposal {
+            ping_amount: ping_amount.clone(),
+            duration_in_seconds,
+            executable_timestamp,
+        });
+        self.config_proposal_event(
+            proposal_id,
+            &ping_amount,
+            duration_in_seconds,
+            executable_timestamp,
+        );
+
+        proposal_id
+    }
+
+    #[endpoint(cancelConfigProposal)]
+    fn cancel_config_proposal(&self, proposal_id: u64) {
+        self.require_config_manager();
+
+        self.remove_config_proposal(proposal_id);
+        self.cancel_config_proposal_event(proposal_id);
+    }
+
+    /// Removes a pending proposal, returning it.
+    fn remove_config_proposal(&self, proposal_id: u64) -> ConfigProposal<Self::Api> {
+        require!(
+            self.config_proposal_ids().swap_remove(&proposal_id),
+            "Config proposal not found"
+        );
+
+        self.config_proposal(proposal_id).take()
+    }
+
+    /// Stores the pending decrease of the timelock as the timelock, once it has taken effect.
+    fn apply_config_timelock_change(&self) {
+        let config_timelock_change = self.config_timelock_change();
+        if config_timelock_change.is_empty() {
+            return;
+        }
+
+        let change = config_timelock_change.get();
+        if self.blockchain().get_block_timestamp() >= change.effective_timestamp {
+            self.config_timelock().set(change.delay_in_seconds);
+            config_timelock_change.clear();
+        }
+    }
+
+    /// How long, in seconds, a proposal made now waits before it can be executed.
+    #[view(getConfigTimelock)]
+    fn get_config_timelock(&self) -> u64 {
+        let config_timelock_change = self.config_timelock_change();
+        if !config_timelock_change.is_empty() {
+            let change = config_timelock_change.get();
+            if self.blockchain().get_block_timestamp() >= change.effective_timestamp {
+                return change.delay_in_seconds;
+            }
+        }
+
+        self.config_timelock().get()
+    }
+
+    /// The decrease of the timelock that has not taken effect yet, if any.
+    #[view(getPendingConfigTimelock)]
+    fn get_pending_config_timelock(&self) -> OptionalValue<ConfigTimelockChange> {
+        let config_timelock_change = self.config_timelock_change();
+        if config_timelock_change.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let change = config_timelock_change.get();
+        if self.blockchain().get_block_timestamp() >= change.effective_timestamp {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(change)
+    }
+
+    /// Lists every pending proposal as (proposal id, proposal).
+    #[view(getPendingConfigProposals)]
+    fn get_pending_config_proposals(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<u64, ConfigProposal<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for proposal_id in self.config_proposal_ids().iter() {
+            result.push((proposal_id, self.config_proposal(proposal_id).get()).into());
+        }
+
+        result
+    }
+
+    // storage
+
+    #[storage_mapper("configTimelock")]
+    fn config_timelock(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("configTimelockChange")]
+    fn config_timelock_change(&self) -> SingleValueMapper<ConfigTimelockChange>;
+
+    #[storage_mapper("lastConfigProposalId")]
+    fn last_config_proposal_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("configProposalIds")]
+    fn config_proposal_ids(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("configProposal")]
+    fn config_proposal(&self, proposal_id: u64) -> SingleValueMapper<ConfigProposal<Self::Api>>;
+
+    // events
+
+    #[event("configTimelockEvent")]
+    fn config_timelock_event(
+        &self,
+        #[indexed] delay_in_seconds: u64,
+        #[indexed] effective_timestamp: u64,
+    );
+
+    #[event("configProposalEvent")]
+    fn config_proposal_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] ping_amount: &BigUint,
+        #[indexed] duration_in_seconds: u64,
+        #[indexed] executable_timestamp: u64,
+    );
+
+    #[event("cancelConfigProposalEvent")]
+    fn cancel_config_proposal_event(&self, #[indexed] proposal_id: u64);
+
+    #[event("executeConfigProposalEvent")]
+    fn execute_config_proposal_event(&self, #[indexed] proposal_id: u64);
+}
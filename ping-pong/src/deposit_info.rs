@@ -0,0 +1,13 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// Snapshot of a user's `ping`, taken at the time of the call.
+/// `pong` refunds exactly what was recorded here, regardless of later configuration changes.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DepositInfo<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub ping_timestamp: u64,
+    pub unlock_timestamp: u64,
+}
@@ -0,0 +1,114 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::deposit_info::DepositInfo;
+
+pub const MAX_PENALTY_RATE: u64 = 10_000;
+
+/// How the `earlyPong` penalty is computed from the rate.
+/// `Flat` - the rate applies to the whole deposit, regardless of the time left.
+/// `Linear` - the rate is scaled by the share of the lock period that is still left.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PenaltyType {
+    Flat,
+    Linear,
+}
+
+/// Lets users take their funds back before the deadline, minus a penalty that goes to the treasury.
+/// The penalty rate is in basis points, `10_000` meaning the whole deposit.
+#[multiversx_sc::module]
+pub trait EarlyPongModule: crate::roles::RolesModule {
+    #[endpoint(setEarlyPongPenalty)]
+    fn set_early_pong_penalty(&self, penalty_type: PenaltyType, penalty_rate: u64) {
+        self.require_config_manager();
+
+        require!(
+            penalty_rate <= MAX_PENALTY_RATE,
+            "Penalty rate cannot exceed 100%"
+        );
+
+        self.penalty_type().set(penalty_type);
+        self.penalty_rate().set(penalty_rate);
+        self.early_pong_penalty_event(penalty_type, penalty_rate);
+    }
+
+    fn set_treasury(&self, treasury: &ManagedAddress) {
+        require!(
+            *treasury != self.blockchain().get_sc_address(),
+            "Treasury cannot be the contract itself"
+        );
+
+        self.treasury().set(treasury);
+        self.treasury_event(treasury);
+    }
+
+    /// Zero once the deposit is unlocked.
+    fn compute_early_pong_penalty(
+        &self,
+        deposit: &DepositInfo<Self::Api>,
+        current_timestamp: u64,
+    ) -> BigUint {
+        if current_timestamp >= deposit.unlock_timestamp {
+            return BigUint::zero();
+        }
+
+        let penalty = &deposit.amount * self.penalty_rate().get() / MAX_PENALTY_RATE;
+        match self.penalty_type().get() {
+            PenaltyType::Flat => penalty,
+            PenaltyType::Linear => {
+                let time_left = deposit.unlock_timestamp - current_timestamp;
+                let lock_duration = deposit.unlock_timestamp - deposit.ping_timestamp;
+                penalty * time_left / lock_duration
+            }
+        }
+    }
+
+    #[view(getEarlyPongPenaltyConfig)]
+    fn get_early_pong_penalty_config(&self) -> MultiValue2<PenaltyType, u64> {
+        (self.penalty_type().get(), self.penalty_rate().get()).into()
+    }
+
+    /// Empty when no treasury was set, in which case `earlyPong` is disabled.
+    #[view(getTreasury)]
+    fn get_treasury(&self) -> OptionalValue<ManagedAddress> {
+        let treasury = self.treasury();
+        if treasury.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(treasury.get())
+    }
+
+    // storage
+
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("penaltyType")]
+    fn penalty_type(&self) -> SingleValueMapper<PenaltyType>;
+
+    #[storage_mapper("penaltyRate")]
+    fn penalty_rate(&self) -> SingleValueMapper<u64>;
+
+    // events
+
+    #[event("earlyPongPenaltyEvent")]
+    fn early_pong_penalty_event(
+        &self,
+        #[indexed] penalty_type: PenaltyType,
+        #[indexed] penalty_rate: u64,
+    );
+
+    #[event("treasuryEvent")]
+    fn treasury_event(&self, #[indexed] treasury: &ManagedAddress);
+
+    #[event("earlyPongEvent")]
+    fn early_pong_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] penalty: &BigUint,
+    );
+}
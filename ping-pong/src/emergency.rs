@@ -0,0 +1,55 @@
+use multiversx_sc::imports::*;
+
+/// A last resort for when something goes wrong: once the owner enters emergency mode, `ping` is blocked
+/// and every deposit can be `pong`-ed right away, whatever its deadline, or refunded in batches by anyone with `emergencyRefund`.
+/// Emergency mode cannot be left.
+#[multiversx_sc::module]
+pub trait EmergencyModule {
+    #[only_owner]
+    #[endpoint(enterEmergency)]
+    fn enter_emergency(&self) {
+        require!(!self.emergency().get(), "Already in emergency mode");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        self.emergency().set(true);
+        self.emergency_timestamp().set(current_timestamp);
+        self.emergency_event(current_timestamp);
+    }
+
+    fn require_not_emergency(&self) {
+        require!(!self.emergency().get(), "Emergency mode is active");
+    }
+
+    fn require_emergency(&self) {
+        require!(self.emergency().get(), "Not in emergency mode");
+    }
+
+    /// Empty while not in emergency mode.
+    #[view(getEmergencyTimestamp)]
+    fn get_emergency_timestamp(&self) -> OptionalValue<u64> {
+        if !self.emergency().get() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.emergency_timestamp().get())
+    }
+
+    // storage
+
+    #[view(isEmergency)]
+    #[storage_mapper("emergency")]
+    fn emergency(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("emergencyTimestamp")]
+    fn emergency_timestamp(&self) -> SingleValueMapper<u64>;
+
+    /// The last deposit looked at by `emergencyRefund`, the next call resuming after it.
+    #[view(getEmergencyRefundCursor)]
+    #[storage_mapper("emergencyRefundCursor")]
+    fn emergency_refund_cursor(&self) -> SingleValueMapper<u64>;
+
+    // events
+
+    #[event("emergencyEvent")]
+    fn emergency_event(&self, #[indexed] timestamp: u64);
+}
@@ -0,0 +1,44 @@
+/// Events emitted by the contract, detailed enough to rebuild every open position from logs.
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("pingEvent")]
+    fn ping_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] ping_timestamp: u64,
+        #[indexed] unlock_timestamp: u64,
+    );
+
+    /// `user` owns the position, `receiver` got the refund.
+    #[event("pongEvent")]
+    fn pong_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    /// Emitted on `init`, on every `upgrade` and on `executeConfigProposal`, with the configuration that applies to new `ping`s.
+    #[event("configEvent")]
+    fn config_event(
+        &self,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] ping_amount: &BigUint,
+        #[indexed] duration_in_seconds: u64,
+    );
+
+    #[event("activationWindowEvent")]
+    fn activation_window_event(
+        &self,
+        #[indexed] ping_start_timestamp: u64,
+        #[indexed] ping_end_timestamp: u64,
+    );
+
+    #[event("multipleDepositsEvent")]
+    fn multiple_deposits_event(&self, #[indexed] enabled: bool);
+}
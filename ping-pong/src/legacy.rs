@@ -0,0 +1,23 @@
+use multiversx_sc::imports::*;
+
+/// Storage left behind by the first version of the contract, which recorded a single `ping` timestamp per user
+/// and refunded the global ping amount on `pong`.
+/// Those users cannot be enumerated, so each of their deposits is turned into a regular one the first time its owner is looked up.
+#[multiversx_sc::module]
+pub trait LegacyModule {
+    /// The ping amount of the first version. Moved to `legacyPingAmount` on the first upgrade, then cleared.
+    #[storage_mapper("pingAmount")]
+    fn first_version_ping_amount(&self) -> SingleValueMapper<BigUint>;
+
+    /// The amount locked by each deposit made before the first upgrade.
+    #[storage_mapper("legacyPingAmount")]
+    fn legacy_ping_amount(&self) -> SingleValueMapper<BigUint>;
+
+    /// The lock duration of each deposit made before the first upgrade.
+    #[storage_mapper("legacyDurationInSeconds")]
+    fn legacy_duration_in_seconds(&self) -> SingleValueMapper<u64>;
+
+    /// Cleared once the deposit of the user was turned into a regular one.
+    #[storage_mapper("userPingTimestamp")]
+    fn legacy_user_ping_timestamp(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+}
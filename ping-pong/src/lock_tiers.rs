@@ -0,0 +1,98 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// The tier of a plain `ping`, locking for `duration_in_seconds` with no reward multiplier. It cannot be changed through `setLockTier`.
+pub const DEFAULT_LOCK_TIER: u64 = 0;
+
+/// A reward multiplier of 1x, in basis points.
+pub const BASE_REWARD_MULTIPLIER: u64 = 10_000;
+
+/// A reward multiplier of 10x, in basis points.
+pub const MAX_REWARD_MULTIPLIER: u64 = 10 * BASE_REWARD_MULTIPLIER;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct LockTier {
+    pub duration_in_seconds: u64,
+    /// Applied to the reward rate, in basis points.
+    pub reward_multiplier: u64,
+}
+
+/// Lets users pick how long they lock their deposit, from a table of tiers set by the owner, longer tiers usually earning more rewards.
+/// A deposit keeps the tier it was made with, even if the owner later changes or removes it.
+#[multiversx_sc::module]
+pub trait LockTiersModule: crate::roles::RolesModule {
+    /// Adds a lock tier, or updates it if it already exists.
+    /// `reward_multiplier` - Optional. Applied to the reward rate, in basis points. Default is 10 000, which is 1x. At most 100 000, which is 10x.
+    #[endpoint(setLockTier)]
+    fn set_lock_tier(
+        &self,
+        tier_id: u64,
+        duration_in_seconds: u64,
+        opt_reward_multiplier: OptionalValue<u64>,
+    ) {
+        self.require_config_manager();
+
+        require!(
+            tier_id != DEFAULT_LOCK_TIER,
+            "Cannot change the default tier"
+        );
+        require!(
+            duration_in_seconds > 0,
+            "Duration in seconds cannot be set to zero"
+        );
+
+        let reward_multiplier = opt_reward_multiplier
+            .into_option()
+            .unwrap_or(BASE_REWARD_MULTIPLIER);
+        require!(
+            reward_multiplier <= MAX_REWARD_MULTIPLIER,
+            "Reward multiplier cannot exceed 10x"
+        );
+        self.lock_tier_ids().insert(tier_id);
+        self.lock_tier(tier_id).set(LockTier {
+            duration_in_seconds,
+            reward_multiplier,
+        });
+        self.lock_tier_event(tier_id, duration_in_seconds, reward_multiplier);
+    }
+
+    /// Stops new `ping`s with a tier. Open positions keep their lock duration and multiplier.
+    #[endpoint(removeLockTier)]
+    fn remove_lock_tier(&self, tier_id: u64) {
+        self.require_config_manager();
+
+        require!(
+            self.lock_tier_ids().swap_remove(&tier_id),
+            "Lock tier not found"
+        );
+
+        self.lock_tier(tier_id).clear();
+        self.remove_lock_tier_event(tier_id);
+    }
+
+    // storage
+
+    #[storage_mapper("lockTierIds")]
+    fn lock_tier_ids(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("lockTier")]
+    fn lock_tier(&self, tier_id: u64) -> SingleValueMapper<LockTier>;
+
+    /// The tier a deposit was made with, as it was at the time.
+    #[storage_mapper("depositLockTier")]
+    fn deposit_lock_tier(&self, deposit_id: u64) -> SingleValueMapper<LockTier>;
+
+    // events
+
+    #[event("lockTierEvent")]
+    fn lock_tier_event(
+        &self,
+        #[indexed] tier_id: u64,
+        #[indexed] duration_in_seconds: u64,
+        #[indexed] reward_multiplier: u64,
+    );
+
+    #[event("removeLockTierEvent")]
+    fn remove_lock_tier_event(&self, #[indexed] tier_id: u64);
+}
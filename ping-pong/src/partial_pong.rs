@@ -0,0 +1,45 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// What happens to the rest of a deposit after `pongPartial`.
+/// `KeepUnlocked` - it stays unlocked, and can be `pong`-ed at any time.
+/// `Relock` - it is locked again for the duration of the lock tier of the deposit, as if it was just `ping`-ed.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PartialPongRemainder {
+    KeepUnlocked,
+    Relock,
+}
+
+/// Lets users take back part of an unlocked deposit with `pongPartial`, leaving the rest as an open position.
+#[multiversx_sc::module]
+pub trait PartialPongModule: crate::roles::RolesModule {
+    #[endpoint(setPartialPongRemainder)]
+    fn set_partial_pong_remainder(&self, remainder: PartialPongRemainder) {
+        self.require_config_manager();
+
+        self.partial_pong_remainder().set(remainder);
+        self.partial_pong_remainder_event(remainder);
+    }
+
+    // storage
+
+    #[view(getPartialPongRemainder)]
+    #[storage_mapper("partialPongRemainder")]
+    fn partial_pong_remainder(&self) -> SingleValueMapper<PartialPongRemainder>;
+
+    // events
+
+    #[event("partialPongRemainderEvent")]
+    fn partial_pong_remainder_event(&self, #[indexed] remainder: PartialPongRemainder);
+
+    /// Emitted after the `pongEvent` of a `pongPartial`, with what is left of the deposit.
+    #[event("partialPongEvent")]
+    fn partial_pong_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] remaining_amount: &BigUint,
+        #[indexed] unlock_timestamp: u64,
+    );
+}
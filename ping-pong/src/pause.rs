@@ -0,0 +1,72 @@
+use multiversx_sc::imports::*;
+
+/// Lets the owner, or a pauser, halt deposits and withdrawals independently,
+/// so that `pong` can stay open while `ping` is stopped during an incident.
+#[multiversx_sc::module]
+pub trait PauseModule: crate::roles::RolesModule {
+    /// Stops `ping` until `unpause` is called. Does not affect `pong`.
+    #[endpoint]
+    fn pause(&self) {
+        self.require_pauser();
+
+        self.ping_paused().set(true);
+        self.pause_event();
+    }
+
+    #[endpoint]
+    fn unpause(&self) {
+        self.require_pauser();
+
+        self.ping_paused().clear();
+        self.unpause_event();
+    }
+
+    /// Stops `pong` until `unpausePong` is called. Does not affect `ping`.
+    #[endpoint(pausePong)]
+    fn pause_pong(&self) {
+        self.require_pauser();
+
+        self.pong_paused().set(true);
+        self.pause_pong_event();
+    }
+
+    #[endpoint(unpausePong)]
+    fn unpause_pong(&self) {
+        self.require_pauser();
+
+        self.pong_paused().clear();
+        self.unpause_pong_event();
+    }
+
+    fn require_ping_not_paused(&self) {
+        require!(!self.ping_paused().get(), "Ping is paused");
+    }
+
+    fn require_pong_not_paused(&self) {
+        require!(!self.pong_paused().get(), "Pong is paused");
+    }
+
+    // storage
+
+    #[view(isPaused)]
+    #[storage_mapper("pingPaused")]
+    fn ping_paused(&self) -> SingleValueMapper<bool>;
+
+    #[view(isPongPaused)]
+    #[storage_mapper("pongPaused")]
+    fn pong_paused(&self) -> SingleValueMapper<bool>;
+
+    // events
+
+    #[event("pauseEvent")]
+    fn pause_event(&self);
+
+    #[event("unpauseEvent")]
+    fn unpause_event(&self);
+
+    #[event("pausePongEvent")]
+    fn pause_pong_event(&self);
+
+    #[event("unpausePongEvent")]
+    fn unpause_pong_event(&self);
+}
@@ -0,0 +1,184 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+pub const MAX_PING_FEE_RATE: u64 = 10_000;
+
+/// How the ping fee is set.
+/// `Rate` - in basis points of the amount locked.
+/// `Flat` - a fixed amount of the default token. `ping`s in other tokens pay no fee.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PingFeeType {
+    Rate,
+    Flat,
+}
+
+/// Charges a fee on top of every `ping`: the user pays the amount to lock plus the fee, and only the former is refunded on `pong`.
+/// Fees are kept apart from the deposits until the owner sends them to the fee collector with `claimFees`.
+#[multiversx_sc::module]
+pub trait PingFeeModule:
+    crate::accepted_tokens::AcceptedTokensModule + crate::roles::RolesModule
+{
+    /// A fee of zero disables it. A flat fee is an amount of the default token, so it is only charged on `ping`s in it.
+    #[endpoint(setPingFee)]
+    fn set_ping_fee(&self, fee_type: PingFeeType, fee: BigUint) {
+        self.require_config_manager();
+
+        require!(
+            fee_type == PingFeeType::Flat || fee <= MAX_PING_FEE_RATE,
+            "Ping fee rate cannot exceed 100%"
+        );
+
+        self.ping_fee_type().set(fee_type);
+        self.ping_fee().set(&fee);
+        self.ping_fee_config_event(fee_type, &fee);
+    }
+
+    #[only_owner]
+    #[endpoint(setFeeCollector)]
+    fn set_fee_collector(&self, fee_collector: ManagedAddress) {
+        require!(
+            fee_collector != self.blockchain().get_sc_address(),
+            "Fee collector cannot be the contract itself"
+        );
+
+        self.fee_collector().set(&fee_collector);
+        self.fee_collector_event(&fee_collector);
+    }
+
+    /// Sends all the fees collected so far, in every token, to the fee collector.
+    #[only_owner]
+    #[endpoint(claimFees)]
+    fn claim_fees(&self) {
+        require!(!self.fee_collector().is_empty(), "Fee collector not set");
+        require!(!self.fee_tokens().is_empty(), "No fees to claim");
+
+        let fee_collector = self.fee_collector().get();
+        for token_id in self.fee_tokens().iter() {
+            let amount = self.accumulated_fees(&token_id).take();
+            self.send().direct(&fee_collector, &token_id, 0, &amount);
+            self.claim_fees_event(&fee_collector, &token_id, &amount);
+        }
+        self.fee_tokens().clear();
+    }
+
+    /// Splits a `ping` payment into the amount to lock and the fee.
+    /// With a rate, the amount to lock is the largest one whose fee still fits in the payment, any rounding dust going to the fee.
+    fn split_ping_fee(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        payment: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let fee = self.ping_fee().get();
+        match self.ping_fee_type().get() {
+            PingFeeType::Flat => {
+                if *token_id != self.accepted_payment_token_id().get() {
+                    return (payment.clone(), BigUint::zero());
+                }
+                if *payment <= fee {
+                    return (BigUint::zero(), payment.clone());
+                }
+
+                (payment - &fee, fee)
+            }
+            PingFeeType::Rate => {
+                let mut amount = payment * MAX_PING_FEE_RATE / (&fee + MAX_PING_FEE_RATE);
+                let next_amount = &amount + 1u32;
+                if &next_amount + &(&next_amount * &fee / MAX_PING_FEE_RATE) <= *payment {
+                    amount = next_amount;
+                }
+
+                let fee = payment - &amount;
+                (amount, fee)
+            }
+        }
+    }
+
+    fn collect_ping_fee(
+        &self,
+        user: &ManagedAddress,
+        deposit_id: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        fee: &BigUint,
+    ) {
+        if *fee == 0 {
+            return;
+        }
+
+        self.fee_tokens().insert(token_id.clone());
+        self.accumulated_fees(token_id)
+            .update(|total| *total += fee);
+        self.ping_fee_event(user, deposit_id, token_id, fee);
+    }
+
+    #[view(getPingFeeConfig)]
+    fn get_ping_fee_config(&self) -> MultiValue2<PingFeeType, BigUint> {
+        (self.ping_fee_type().get(), self.ping_fee().get()).into()
+    }
+
+    #[view(getFeeCollector)]
+    fn get_fee_collector(&self) -> OptionalValue<ManagedAddress> {
+        let fee_collector = self.fee_collector();
+        if fee_collector.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(fee_collector.get())
+    }
+
+    /// Lists the fees not claimed yet, as (token, amount).
+    #[view(getAccumulatedFees)]
+    fn get_accumulated_fees(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token_id in self.fee_tokens().iter() {
+            let amount = self.accumulated_fees(&token_id).get();
+            result.push((token_id, amount).into());
+        }
+
+        result
+    }
+
+    // storage
+
+    #[storage_mapper("pingFeeType")]
+    fn ping_fee_type(&self) -> SingleValueMapper<PingFeeType>;
+
+    #[storage_mapper("pingFee")]
+    fn ping_fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("feeCollector")]
+    fn fee_collector(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("feeTokens")]
+    fn fee_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("accumulatedFees")]
+    fn accumulated_fees(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // events
+
+    #[event("pingFeeConfigEvent")]
+    fn ping_fee_config_event(&self, #[indexed] fee_type: PingFeeType, #[indexed] fee: &BigUint);
+
+    #[event("feeCollectorEvent")]
+    fn fee_collector_event(&self, #[indexed] fee_collector: &ManagedAddress);
+
+    #[event("pingFeeEvent")]
+    fn ping_fee_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] fee: &BigUint,
+    );
+
+    #[event("claimFeesEvent")]
+    fn claim_fees_event(
+        &self,
+        #[indexed] fee_collector: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+}
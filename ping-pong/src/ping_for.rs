@@ -0,0 +1,61 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// Which side of a `pingFor` deposit is allowed to `pong` it.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PingForPonger {
+    Beneficiary,
+    Funder,
+}
+
+/// Lets a funder, such as a custody service, lock a payment under another address with `pingFor`.
+/// The funder is recorded next to the deposit, and the owner decides which of the two can `pong` it.
+#[multiversx_sc::module]
+pub trait PingForModule: crate::roles::RolesModule {
+    /// Only applies to `pingFor` deposits made from now on.
+    #[endpoint(setPingForPonger)]
+    fn set_ping_for_ponger(&self, ponger: PingForPonger) {
+        self.require_config_manager();
+
+        self.ping_for_ponger().set(ponger);
+        self.ping_for_ponger_event(ponger);
+    }
+
+    /// The funder of a `pingFor` deposit, empty for deposits funded by their owner.
+    #[view(getDepositFunder)]
+    fn get_deposit_funder(&self, deposit_id: u64) -> OptionalValue<ManagedAddress> {
+        let funder = self.deposit_funder(deposit_id);
+        if funder.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(funder.get())
+    }
+
+    // storage
+
+    #[view(getPingForPonger)]
+    #[storage_mapper("pingForPonger")]
+    fn ping_for_ponger(&self) -> SingleValueMapper<PingForPonger>;
+
+    #[storage_mapper("depositFunder")]
+    fn deposit_funder(&self, deposit_id: u64) -> SingleValueMapper<ManagedAddress>;
+
+    /// The ponger setting in force when a `pingFor` deposit was made.
+    #[storage_mapper("depositPingForPonger")]
+    fn deposit_ping_for_ponger(&self, deposit_id: u64) -> SingleValueMapper<PingForPonger>;
+
+    // events
+
+    #[event("pingForEvent")]
+    fn ping_for_event(
+        &self,
+        #[indexed] funder: &ManagedAddress,
+        #[indexed] beneficiary: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+    );
+
+    #[event("pingForPongerEvent")]
+    fn ping_for_ponger_event(&self, #[indexed] ponger: PingForPonger);
+}
@@ -2,28 +2,95 @@
 
 use multiversx_sc::imports::*;
 
-/// A contract that allows anyone to send a fixed sum, locks it for a while and then allows users to take it back.
+pub mod accepted_tokens;
+pub mod auto_renew;
+pub mod config_proposals;
+pub mod deposit_info;
+pub mod early_pong;
+pub mod emergency;
+pub mod events;
+pub mod legacy;
+pub mod lock_tiers;
+pub mod partial_pong;
+pub mod pause;
+pub mod ping_fee;
+pub mod ping_for;
+pub mod receipt;
+pub mod rewards;
+pub mod roles;
+pub mod stats;
+
+use deposit_info::DepositInfo;
+use lock_tiers::{LockTier, BASE_REWARD_MULTIPLIER, DEFAULT_LOCK_TIER};
+use partial_pong::PartialPongRemainder;
+use ping_for::PingForPonger;
+
+/// A contract that allows anyone to send a fixed sum, or any sum within a set range, locks it for a while and then allows users to take it back.
 /// Sending funds to the contract is called "ping".
 /// Taking the same funds back is called "pong".
 ///
 /// Restrictions:
-/// - Only the set amount can be `ping`-ed, no more, no less.
-/// - `pong` can only be called after a certain period after `ping`.
+/// - Only the accepted tokens can be `ping`-ed, each with its set amount, no more, no less, or within its set range.
+/// - NFT, SFT and meta-ESDT collections can be accepted too. `pong` returns the same nonce that was `ping`-ed.
+/// - `pong` can only be called after a certain period after `ping`, set globally or by the lock tier picked on `ping`. Before that, `earlyPong` refunds the deposit minus a penalty.
+/// - After that period, `pongPartial` takes back part of a deposit and leaves the rest as an open position.
+/// - Users can push back the unlock time of a deposit, or have it locked again if they do not `pong` it within a grace period.
+/// - If the owner sets a ping fee, it is paid on top of the amount to lock and is not refunded on `pong`.
+/// - A user can hold a single deposit at a time, unless the owner enables multiple deposits.
+/// - A deposit made with `pingFor` can be `pong`-ed either by its beneficiary or by its funder, as configured by the owner when it was made.
+/// - `ping` is only accepted inside the activation window, if one is set.
+/// - Once matured, deposits left behind can be refunded in batches by anyone, through `pongMatured`.
+/// - The owner, or a pauser, can pause `ping` and `pong` separately.
+/// - The owner can grant pausers and config managers part of its admin power.
+/// - Once set, `ping_amount` and `duration_in_seconds` can only be changed through proposals, executable once their timelock has passed.
+/// - In emergency mode, which cannot be left, `ping` is blocked and every deposit can be `pong`-ed or refunded right away.
+/// - Once the owner issues the receipt collection and sets up its transfers, each `ping` mints a receipt NFT. Whoever sends it back can `pong` the deposit.
+/// - `pong` returns exactly what was `ping`-ed, even if the configuration was changed in the meantime, plus a reward if the owner funded a reward pool.
 #[multiversx_sc::contract]
-pub trait PingPong {
+pub trait PingPong:
+    accepted_tokens::AcceptedTokensModule
+    + auto_renew::AutoRenewModule
+    + config_proposals::ConfigProposalsModule
+    + early_pong::EarlyPongModule
+    + emergency::EmergencyModule
+    + events::EventsModule
+    + legacy::LegacyModule
+    + lock_tiers::LockTiersModule
+    + partial_pong::PartialPongModule
+    + pause::PauseModule
+    + ping_fee::PingFeeModule
+    + ping_for::PingForModule
+    + receipt::ReceiptModule
+    + rewards::RewardsModule
+    + roles::RolesModule
+    + stats::StatsModule
+{
     /// Necessary configuration when deploying:
-    /// `ping_amount` - the exact amount that needs to be sent when `ping`-ing.  
+    /// `ping_amount` - the exact amount of the default token that needs to be sent when `ping`-ing, or the minimum in range mode.  
     /// `duration_in_seconds` - how much time (in seconds) until `pong` can be called after the initial `ping` call  
-    /// `token_id` - Optional. The Token Identifier of the token that is going to be used. Default is "EGLD".
+    /// `token_id` - Optional. The Token Identifier of the default token. Other tokens can be added later by the owner. Default is "EGLD".  
+    /// `activation_window` - Optional. The start and end timestamps between which `ping` is accepted, 0 meaning no bound. Default is no window.  
+    /// `max_ping_amount` - Optional. If set, any amount between `ping_amount` and `max_ping_amount` can be `ping`-ed. Default is exact mode.  
+    /// `treasury` - Optional. The address receiving the `earlyPong` penalties. Default is none, which disables `earlyPong`.
     #[init]
+    #[allow_multiple_var_args]
     fn init(
         &self,
         ping_amount: BigUint,
         duration_in_seconds: u64,
         opt_token_id: OptionalValue<EgldOrEsdtTokenIdentifier>,
+        opt_activation_window: OptionalValue<MultiValue2<u64, u64>>,
+        opt_max_ping_amount: OptionalValue<BigUint>,
+        opt_treasury: OptionalValue<ManagedAddress>,
     ) {
-        require!(ping_amount > 0, "Ping amount cannot be set to zero");
-        self.ping_amount().set(&ping_amount);
+        let token_id = match opt_token_id {
+            OptionalValue::Some(t) => t,
+            OptionalValue::None => EgldOrEsdtTokenIdentifier::egld(),
+        };
+        self.accepted_payment_token_id().set(&token_id);
+
+        let max_ping_amount = opt_max_ping_amount.into_option().unwrap_or_default();
+        self.set_token_ping_amount(&token_id, &ping_amount, &max_ping_amount);
 
         require!(
             duration_in_seconds > 0,
@@ -31,86 +98,830 @@ pub trait PingPong {
         );
         self.duration_in_seconds().set(duration_in_seconds);
 
-        let token_id = match opt_token_id {
-            OptionalValue::Some(t) => t,
-            OptionalValue::None => EgldOrEsdtTokenIdentifier::egld(),
+        self.config_event(&token_id, &ping_amount, duration_in_seconds);
+
+        let (ping_start_timestamp, ping_end_timestamp) = match opt_activation_window {
+            OptionalValue::Some(window) => window.into_tuple(),
+            OptionalValue::None => (0, 0),
         };
-        self.accepted_payment_token_id().set(&token_id);
+        self.set_activation_window(ping_start_timestamp, ping_end_timestamp);
+
+        if let OptionalValue::Some(treasury) = opt_treasury {
+            self.set_treasury(&treasury);
+        }
     }
 
+    /// `ping_amount`, `duration_in_seconds` - Only set on the upgrade from the first version. Afterwards they must be the current ones,
+    /// as they can only be changed through config proposals.  
+    /// `activation_window` - Optional. If missing, the current window is kept.  
+    /// `max_ping_amount` - Optional. If missing, the current one is kept. Zero switches back to exact mode.
+    /// It is not timelocked: it only changes the amounts new pings may send above `ping_amount`, and leaves open deposits as they are.  
+    /// `treasury` - Optional. If missing, the current one is kept.
     #[upgrade]
-    fn upgrade(&self, ping_amount: BigUint, duration_in_seconds: u64) {
+    #[allow_multiple_var_args]
+    fn upgrade(
+        &self,
+        ping_amount: BigUint,
+        duration_in_seconds: u64,
+        opt_activation_window: OptionalValue<MultiValue2<u64, u64>>,
+        opt_max_ping_amount: OptionalValue<BigUint>,
+        opt_treasury: OptionalValue<ManagedAddress>,
+    ) {
+        let activation_window = match opt_activation_window {
+            OptionalValue::Some(window) => window,
+            OptionalValue::None => self.get_activation_window(),
+        };
+        let max_ping_amount = match opt_max_ping_amount {
+            OptionalValue::Some(max_ping_amount) => max_ping_amount,
+            OptionalValue::None => self.get_max_ping_amount(),
+        };
+        let token_id = self.accepted_payment_token_id().get();
+        if !self.token_ping_amount(&token_id).is_empty() {
+            require!(
+                ping_amount == self.get_ping_amount()
+                    && duration_in_seconds == self.duration_in_seconds().get(),
+                "Ping amount and duration can only be changed through config proposals"
+            );
+        }
+        self.save_legacy_config();
+
         self.init(
             ping_amount,
             duration_in_seconds,
-            OptionalValue::Some(self.accepted_payment_token_id().get()),
+            OptionalValue::Some(token_id),
+            OptionalValue::Some(activation_window),
+            OptionalValue::Some(max_ping_amount),
+            opt_treasury,
         )
     }
 
+    /// On the first upgrade from the first version, keeps the configuration its deposits were made with, before `init` overwrites it.
+    /// Its contract balance is all locked in those deposits, and is counted as such in the stats.
+    fn save_legacy_config(&self) {
+        let first_version_ping_amount = self.first_version_ping_amount();
+        if first_version_ping_amount.is_empty() {
+            return;
+        }
+
+        self.legacy_ping_amount()
+            .set(first_version_ping_amount.take());
+        self.legacy_duration_in_seconds()
+            .set(self.duration_in_seconds().get());
+
+        let token_id = self.accepted_payment_token_id().get();
+        let legacy_locked = self.blockchain().get_sc_balance(&token_id, 0);
+        self.record_legacy_locked(&token_id, &legacy_locked);
+    }
+
     // endpoints
 
+    /// Applies a proposal made with `proposeConfig`, once its timelock has passed.
+    /// The max ping amount of the default token is kept, so the new ping amount must stay below it in range mode.
+    #[endpoint(executeConfigProposal)]
+    fn execute_config_proposal(&self, proposal_id: u64) {
+        self.require_config_manager();
+
+        let proposal = self.remove_config_proposal(proposal_id);
+        require!(
+            self.blockchain().get_block_timestamp() >= proposal.executable_timestamp,
+            "Config proposal is still timelocked"
+        );
+
+        self.execute_config_proposal_event(proposal_id);
+
+        let token_id = self.accepted_payment_token_id().get();
+        let max_ping_amount = self.get_max_ping_amount();
+        self.set_token_ping_amount(&token_id, &proposal.ping_amount, &max_ping_amount);
+        self.duration_in_seconds().set(proposal.duration_in_seconds);
+
+        self.config_event(
+            &token_id,
+            &proposal.ping_amount,
+            proposal.duration_in_seconds,
+        );
+    }
+
+    /// Allows users to hold several deposits at once, each with its own id and unlock time.
+    /// Disabling it only stops new `ping`s from users that already hold a deposit.
+    #[endpoint(setMultipleDeposits)]
+    fn set_multiple_deposits(&self, enabled: bool) {
+        self.require_config_manager();
+
+        self.multiple_deposits_enabled().set(enabled);
+        self.multiple_deposits_event(enabled);
+    }
+
     /// User sends some tokens to be locked in the contract for a period of time.
+    /// `tier_id` - Optional. The lock tier, setting the lock duration and reward multiplier. Default is tier 0, which locks for `duration_in_seconds`.
     #[payable]
     #[endpoint]
-    fn ping(&self) {
-        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
-        require!(
-            payment_token == self.accepted_payment_token_id().get(),
-            "Invalid payment token"
-        );
+    fn ping(&self, opt_tier_id: OptionalValue<u64>) {
+        let caller = self.blockchain().get_caller();
+        let tier_id = opt_tier_id.into_option().unwrap_or(DEFAULT_LOCK_TIER);
+        let deposit_id = self.lock_deposit(&caller, tier_id, false);
+        self.send_receipt(deposit_id);
+    }
+
+    /// Locks the payment under `beneficiary` instead of the caller, recording the caller as the funder.
+    /// Which of the two can `pong` it is decided by the owner with `setPingForPonger`, and fixed for the deposit at the time of the call.
+    /// Such a deposit does not use up the single deposit of the beneficiary: they can still `ping` for themselves.
+    /// `tier_id` - Optional. The lock tier, as for `ping`.
+    /// Returns the id of the new deposit.
+    #[payable]
+    #[endpoint(pingFor)]
+    fn ping_for(&self, beneficiary: ManagedAddress, opt_tier_id: OptionalValue<u64>) -> u64 {
         require!(
-            payment_amount == self.ping_amount().get(),
-            "The payment must match the fixed ping amount"
+            beneficiary != self.blockchain().get_sc_address(),
+            "Cannot ping for the contract itself"
         );
 
         let caller = self.blockchain().get_caller();
-        require!(!self.did_user_ping(&caller), "Already pinged");
+        let tier_id = opt_tier_id.into_option().unwrap_or(DEFAULT_LOCK_TIER);
+        let funded = caller != beneficiary;
+        let deposit_id = self.lock_deposit(&beneficiary, tier_id, funded);
+        if funded {
+            self.deposit_funder(deposit_id).set(&caller);
+            self.deposit_ping_for_ponger(deposit_id)
+                .set(self.ping_for_ponger().get());
+            self.ping_for_event(&caller, &beneficiary, deposit_id);
+        }
+        self.send_receipt(deposit_id);
 
-        let current_block_timestamp = self.blockchain().get_block_timestamp();
-        self.user_ping_timestamp(&caller)
-            .set(current_block_timestamp);
+        deposit_id
     }
 
     /// User can take back funds from the contract.
     /// Can only be called after expiration.
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit.
+    /// The receipt of the deposit, if it has one, must be sent along.
+    #[payable]
     #[endpoint]
-    fn pong(&self) {
+    fn pong(&self, opt_deposit_id: OptionalValue<u64>) {
+        let caller = self.blockchain().get_caller();
+        self.pong_deposit(opt_deposit_id, &caller);
+    }
+
+    /// Same as `pong`, but the refund is sent to `destination` instead of the caller.
+    #[payable]
+    #[endpoint(pongTo)]
+    fn pong_to(&self, destination: ManagedAddress, opt_deposit_id: OptionalValue<u64>) {
+        require!(
+            destination != self.blockchain().get_sc_address(),
+            "Cannot pong to the contract itself"
+        );
+
+        self.pong_deposit(opt_deposit_id, &destination);
+    }
+
+    /// Takes back a deposit before its deadline, minus the penalty, which is sent to the treasury.
+    /// Once the deposit is unlocked there is no penalty, same as `pong`.
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit.
+    #[payable]
+    #[endpoint(earlyPong)]
+    fn early_pong(&self, opt_deposit_id: OptionalValue<u64>) {
+        require!(!self.treasury().is_empty(), "Early pong is not enabled");
+
         let caller = self.blockchain().get_caller();
-        require!(self.did_user_ping(&caller), "Must ping first");
+        let deposit_id = self.require_pongable_deposit(&caller, opt_deposit_id);
+        let deposit = self.load_deposit(deposit_id);
 
-        let pong_enable_timestamp = self.get_pong_enable_timestamp(&caller);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let (penalty, reward) = if self.is_unlocked(&deposit, current_timestamp) {
+            (BigUint::zero(), self.take_reward(deposit_id, &deposit))
+        } else {
+            (
+                self.compute_early_pong_penalty(&deposit, current_timestamp),
+                BigUint::zero(),
+            )
+        };
+        let user = self.deposit_owner(deposit_id).get();
+        let token_id = deposit.token_id.clone();
+        let token_nonce = self.deposit_token_nonce(deposit_id).get();
+        self.withdraw_deposit(deposit_id, deposit, &caller, &penalty, &reward);
+
+        if penalty > 0 {
+            self.send()
+                .direct(&self.treasury().get(), &token_id, token_nonce, &penalty);
+            self.early_pong_event(&user, deposit_id, &token_id, &penalty);
+        }
+    }
+
+    /// Takes back `amount` out of an unlocked deposit. The rest of it keeps its unlock time or is locked again, as configured by the owner.
+    /// The reward accrued by the whole deposit is paid out. A new receipt, for what is left, replaces the one sent along.
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit.
+    #[payable]
+    #[endpoint(pongPartial)]
+    fn pong_partial(&self, amount: BigUint, opt_deposit_id: OptionalValue<u64>) {
+        let caller = self.blockchain().get_caller();
+        let deposit_id = self.require_pongable_deposit(&caller, opt_deposit_id);
+
+        let mut deposit = self.load_deposit(deposit_id);
         let current_timestamp = self.blockchain().get_block_timestamp();
         require!(
-            current_timestamp >= pong_enable_timestamp,
+            self.is_unlocked(&deposit, current_timestamp),
             "Cannot pong before deadline"
         );
+        require!(amount > 0, "Amount must be greater than zero");
+        require!(amount <= deposit.amount, "Amount exceeds the deposit");
 
-        self.user_ping_timestamp(&caller).clear();
+        let reward = self.take_reward(deposit_id, &deposit);
+        if amount == deposit.amount {
+            self.withdraw_deposit(deposit_id, deposit, &caller, &BigUint::zero(), &reward);
+            return;
+        }
 
-        let token_id = self.accepted_payment_token_id().get();
-        let amount = self.ping_amount().get();
+        deposit.amount -= &amount;
+        let token_nonce = self.deposit_token_nonce(deposit_id).get();
+        match self.partial_pong_remainder().get() {
+            PartialPongRemainder::KeepUnlocked => self.deposit_reward_rate(deposit_id).clear(),
+            PartialPongRemainder::Relock => {
+                let lock_tier = self.deposit_lock_tier(deposit_id).get();
+                deposit.ping_timestamp = current_timestamp;
+                deposit.unlock_timestamp = current_timestamp + lock_tier.duration_in_seconds;
+                if token_nonce == 0 {
+                    self.snapshot_reward_rate(deposit_id, lock_tier.reward_multiplier);
+                }
+            }
+        }
+        self.record_pong(&deposit.token_id, &amount);
+
+        let user = self.deposit_owner(deposit_id).get();
+        self.send().direct(
+            &caller,
+            &deposit.token_id,
+            token_nonce,
+            &(&amount + &reward),
+        );
+        self.pong_event(&user, &caller, deposit_id, &deposit.token_id, &amount);
+        if reward > 0 {
+            self.reward_event(&user, deposit_id, &deposit.token_id, &reward);
+        }
+        self.partial_pong_event(&user, deposit_id, &deposit.amount, deposit.unlock_timestamp);
+
+        self.update_deposit(deposit_id, deposit, &caller);
+    }
+
+    /// Pushes back the unlock time of a deposit by `extra_seconds`, counted from now if the deposit is already unlocked.
+    /// The reward accrues over the extra seconds too, but not over the time the deposit sat unlocked.
+    /// The receipt of the deposit, if it has one, must be sent along, and is replaced by one showing the new unlock time.
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit.
+    #[payable]
+    #[endpoint(extendLock)]
+    fn extend_lock(&self, extra_seconds: u64, opt_deposit_id: OptionalValue<u64>) {
+        require!(extra_seconds > 0, "Extra seconds must be greater than zero");
+
+        let caller = self.blockchain().get_caller();
+        let deposit_id = self.require_pongable_deposit(&caller, opt_deposit_id);
+
+        let mut deposit = self.load_deposit(deposit_id);
+        self.restart_unlocked_deposit(&mut deposit);
+        deposit.unlock_timestamp = deposit
+            .unlock_timestamp
+            .checked_add(extra_seconds)
+            .unwrap_or_else(|| sc_panic!("Unlock timestamp overflow"));
+        self.lock_extended_event(
+            &self.deposit_owner(deposit_id).get(),
+            deposit_id,
+            deposit.unlock_timestamp,
+        );
+        self.update_deposit(deposit_id, deposit, &caller);
+    }
+
+    /// Opts a deposit in or out of auto-renew, keeping the current grace period for it.
+    /// A deposit that opts in while already unlocked starts its grace period now, without reward for the time it sat unlocked.
+    /// Opting out keeps the unlock time reached by the past renewals.
+    /// The receipt of the deposit, if it has one, must be sent along, and is replaced by a new one.
+    /// `deposit_id` - Optional. Can be left out when the user holds a single deposit.
+    #[payable]
+    #[endpoint(setAutoRenew)]
+    fn set_auto_renew(&self, enabled: bool, opt_deposit_id: OptionalValue<u64>) {
+        require!(
+            !enabled || self.auto_renew_grace_period().get() > 0,
+            "Auto-renew is not enabled"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let deposit_id = self.require_pongable_deposit(&caller, opt_deposit_id);
 
-        self.send().direct(&caller, &token_id, 0, &amount);
-        self.pong_event(&caller);
+        let mut deposit = self.load_deposit(deposit_id);
+        if enabled && !self.deposit_auto_renew(deposit_id).get() {
+            self.restart_unlocked_deposit(&mut deposit);
+        }
+        self.deposit_auto_renew(deposit_id).set(enabled);
+        if enabled {
+            self.deposit_auto_renew_grace_period(deposit_id)
+                .set(self.auto_renew_grace_period().get());
+        } else {
+            self.deposit_auto_renew_grace_period(deposit_id).clear();
+        }
+        self.auto_renew_event(&self.deposit_owner(deposit_id).get(), deposit_id, enabled);
+        self.update_deposit(deposit_id, deposit, &caller);
+    }
+
+    /// Takes back every deposit of the user that can already be `pong`-ed.
+    /// Deposits that only their funder can `pong` are skipped, and so are deposits whose receipt was not sent along.
+    /// The deposits of the receipts sent along are `pong`-ed too, whoever made them.
+    #[payable]
+    #[endpoint(pongAll)]
+    fn pong_all(&self) {
+        self.require_pong_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        self.migrate_legacy_deposit(&caller);
+        let receipt_nonces = self.get_paid_receipt_nonces();
+        require!(
+            self.did_user_ping(&caller) || !receipt_nonces.is_empty(),
+            "Must ping first"
+        );
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut matured_deposit_ids: ManagedVec<u64> = self
+            .user_deposit_ids(&caller)
+            .iter()
+            .filter(|deposit_id| {
+                caller == self.get_ponger(*deposit_id)
+                    && self.deposit_receipt_nonce(*deposit_id).is_empty()
+                    && self.is_unlocked(&self.load_deposit(*deposit_id), current_timestamp)
+            })
+            .collect();
+        for receipt_nonce in receipt_nonces.iter() {
+            let deposit_id = self.require_receipt_deposit(receipt_nonce);
+            self.take_over_receipt_deposit(&caller, deposit_id);
+            require!(
+                self.is_unlocked(&self.load_deposit(deposit_id), current_timestamp),
+                "Cannot pong before deadline"
+            );
+            matured_deposit_ids.push(deposit_id);
+        }
+        require!(
+            !matured_deposit_ids.is_empty(),
+            "Cannot pong before deadline"
+        );
+
+        for deposit_id in matured_deposit_ids.iter() {
+            let deposit = self.load_deposit(deposit_id);
+            let reward = self.take_reward(deposit_id, &deposit);
+            self.withdraw_deposit(deposit_id, deposit, &caller, &BigUint::zero(), &reward);
+        }
+    }
+
+    /// Refunds the matured deposits to whoever can `pong` them, so that positions left behind can be wound down. Anyone can call it.
+    /// Deposits are walked by id, starting after the one the previous call stopped at, and starting over once past the last one.
+    /// At most `max_count` deposits are looked at per call, to keep within the gas limit.
+    /// Deposits with a receipt are left for the receipt holder to `pong`, and deposits owed to a smart contract,
+    /// which might not accept the transfer and fail the whole call, for the contract to `pong` itself.
+    /// Deposits whose reward the pool cannot cover are skipped too, when the empty pool policy is to fail, so that they keep their reward.
+    /// Returns the number of deposits refunded.
+    #[endpoint(pongMatured)]
+    fn pong_matured(&self, max_count: u64) -> u64 {
+        self.require_pong_not_paused();
+        require!(max_count > 0, "Max count must be greater than zero");
+
+        self.refund_unlocked_deposits(self.pong_matured_cursor(), max_count)
+    }
+
+    /// Refunds every deposit right away, whatever its deadline, to whoever can `pong` it. Only available in emergency mode, to anyone.
+    /// Works in chunks of at most `max_count` deposits, like `pongMatured`, and skips the same deposits.
+    /// Returns the number of deposits refunded.
+    #[endpoint(emergencyRefund)]
+    fn emergency_refund(&self, max_count: u64) -> u64 {
+        self.require_emergency();
+        require!(max_count > 0, "Max count must be greater than zero");
+
+        self.refund_unlocked_deposits(self.emergency_refund_cursor(), max_count)
+    }
+
+    /// Walks at most `max_count` deposits by id, starting after `cursor`, and refunds those that can be `pong`-ed to whoever can `pong` them.
+    /// Returns the number of deposits refunded.
+    fn refund_unlocked_deposits(&self, cursor: SingleValueMapper<u64>, max_count: u64) -> u64 {
+        let last_deposit_id = self.last_deposit_id().get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut deposit_id = cursor.get();
+        let mut refunded_count = 0;
+        for _ in 0..core::cmp::min(max_count, last_deposit_id) {
+            deposit_id = if deposit_id >= last_deposit_id {
+                1
+            } else {
+                deposit_id + 1
+            };
+            if self.deposit(deposit_id).is_empty()
+                || !self.deposit_receipt_nonce(deposit_id).is_empty()
+            {
+                continue;
+            }
+
+            let receiver = self.get_ponger(deposit_id);
+            if self.blockchain().is_smart_contract(&receiver) {
+                continue;
+            }
+
+            let deposit = self.load_deposit(deposit_id);
+            if !self.is_unlocked(&deposit, current_timestamp) {
+                continue;
+            }
+
+            let reward = match self.try_take_reward(deposit_id, &deposit) {
+                Some(reward) => reward,
+                None => continue,
+            };
+            self.withdraw_deposit(deposit_id, deposit, &receiver, &BigUint::zero(), &reward);
+            refunded_count += 1;
+        }
+        cursor.set(deposit_id);
+
+        refunded_count
+    }
+
+    fn pong_deposit(&self, opt_deposit_id: OptionalValue<u64>, receiver: &ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let deposit_id = self.require_pongable_deposit(&caller, opt_deposit_id);
+
+        let deposit = self.load_deposit(deposit_id);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            self.is_unlocked(&deposit, current_timestamp),
+            "Cannot pong before deadline"
+        );
+
+        let reward = self.take_reward(deposit_id, &deposit);
+        self.withdraw_deposit(deposit_id, deposit, receiver, &BigUint::zero(), &reward);
+    }
+
+    /// Turns the deposit the user made under the first version of the contract, if any, into a regular one.
+    fn migrate_legacy_deposit(&self, address: &ManagedAddress) {
+        let legacy_user_ping_timestamp = self.legacy_user_ping_timestamp(address);
+        if legacy_user_ping_timestamp.is_empty() {
+            return;
+        }
+
+        let ping_timestamp = legacy_user_ping_timestamp.take();
+        let lock_tier = LockTier {
+            duration_in_seconds: self.legacy_duration_in_seconds().get(),
+            reward_multiplier: BASE_REWARD_MULTIPLIER,
+        };
+        let deposit = DepositInfo {
+            token_id: self.accepted_payment_token_id().get(),
+            amount: self.legacy_ping_amount().get(),
+            ping_timestamp,
+            unlock_timestamp: ping_timestamp + lock_tier.duration_in_seconds,
+        };
+
+        let deposit_id = self.last_deposit_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.deposit(deposit_id).set(deposit);
+        self.deposit_owner(deposit_id).set(address);
+        self.deposit_lock_tier(deposit_id).set(lock_tier);
+        self.user_deposit_ids(address).insert(deposit_id);
+        self.active_users().insert(address.clone());
+    }
+
+    /// Resolves the deposit to `pong` and checks that the caller is allowed to, regardless of its deadline.
+    /// A receipt sent along designates its deposit, and lets the caller `pong` it whoever made it.
+    fn require_pongable_deposit(
+        &self,
+        caller: &ManagedAddress,
+        opt_deposit_id: OptionalValue<u64>,
+    ) -> u64 {
+        self.require_pong_not_paused();
+        self.migrate_legacy_deposit(caller);
+
+        let receipt_nonces = self.get_paid_receipt_nonces();
+        if !receipt_nonces.is_empty() {
+            require!(receipt_nonces.len() == 1, "Invalid receipt");
+            let deposit_id = self.require_receipt_deposit(receipt_nonces.get(0));
+            if let OptionalValue::Some(expected_deposit_id) = opt_deposit_id {
+                require!(deposit_id == expected_deposit_id, "Invalid receipt");
+            }
+            self.take_over_receipt_deposit(caller, deposit_id);
+
+            return deposit_id;
+        }
+
+        let deposit_id = match opt_deposit_id {
+            OptionalValue::Some(deposit_id) => deposit_id,
+            OptionalValue::None => {
+                require!(self.did_user_ping(caller), "Must ping first");
+
+                let user_deposit_ids = self.user_deposit_ids(caller);
+                require!(user_deposit_ids.len() == 1, "Deposit id required");
+                user_deposit_ids.get_by_index(1)
+            }
+        };
+        require!(!self.deposit(deposit_id).is_empty(), "Deposit not found");
+        require!(
+            *caller == self.get_ponger(deposit_id),
+            "Caller cannot pong this deposit"
+        );
+        require!(
+            self.deposit_receipt_nonce(deposit_id).is_empty(),
+            "Receipt required"
+        );
+
+        deposit_id
+    }
+
+    /// `funded` - Whether someone else than `owner` pays for the deposit, which then does not count against its single deposit.
+    fn lock_deposit(&self, owner: &ManagedAddress, tier_id: u64, funded: bool) -> u64 {
+        self.require_not_emergency();
+        self.require_ping_not_paused();
+        self.require_ping_window_open();
+        let lock_tier = self.require_lock_tier(tier_id);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        let payment_token = payment.token_identifier;
+        let token_nonce = payment.token_nonce;
+        require!(
+            self.accepted_tokens().contains(&payment_token),
+            "Invalid payment token"
+        );
+        self.require_valid_ping_nonce(&payment_token, token_nonce);
+
+        // fees and rewards are only handled in fungible tokens, so a payment with a nonce has neither
+        let (payment_amount, fee) = if token_nonce == 0 {
+            self.split_ping_fee(&payment_token, &payment.amount)
+        } else {
+            (payment.amount, BigUint::zero())
+        };
+        self.require_valid_ping_amount(&payment_token, &payment_amount);
+
+        self.migrate_legacy_deposit(owner);
+        if !funded && !self.multiple_deposits_enabled().get() {
+            require!(!self.has_own_deposit(owner), "Already pinged");
+        }
+
+        let current_block_timestamp = self.blockchain().get_block_timestamp();
+        let unlock_timestamp = current_block_timestamp + lock_tier.duration_in_seconds;
+        let deposit = DepositInfo {
+            token_id: payment_token,
+            amount: payment_amount,
+            ping_timestamp: current_block_timestamp,
+            unlock_timestamp,
+        };
+
+        let deposit_id = self.last_deposit_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.ping_event(
+            owner,
+            deposit_id,
+            &deposit.token_id,
+            &deposit.amount,
+            deposit.ping_timestamp,
+            deposit.unlock_timestamp,
+        );
+        self.record_ping(&deposit.token_id, &deposit.amount);
+        self.collect_ping_fee(owner, deposit_id, &deposit.token_id, &fee);
+        self.deposit(deposit_id).set(deposit);
+        self.deposit_owner(deposit_id).set(owner);
+        if token_nonce == 0 {
+            self.snapshot_reward_rate(deposit_id, lock_tier.reward_multiplier);
+        } else {
+            self.deposit_token_nonce(deposit_id).set(token_nonce);
+        }
+        self.deposit_lock_tier(deposit_id).set(lock_tier);
+        self.user_deposit_ids(owner).insert(deposit_id);
+        self.active_users().insert(owner.clone());
+
+        deposit_id
+    }
+
+    fn withdraw_deposit(
+        &self,
+        deposit_id: u64,
+        deposit: DepositInfo<Self::Api>,
+        receiver: &ManagedAddress,
+        penalty: &BigUint,
+        reward: &BigUint,
+    ) {
+        let owner = self.deposit_owner(deposit_id).take();
+        self.burn_receipt(deposit_id);
+        self.remove_user_deposit(&owner, deposit_id);
+        self.deposit(deposit_id).clear();
+        self.deposit_funder(deposit_id).clear();
+        self.deposit_ping_for_ponger(deposit_id).clear();
+        let token_nonce = self.deposit_token_nonce(deposit_id).take();
+        self.deposit_reward_rate(deposit_id).clear();
+        self.deposit_auto_renew(deposit_id).clear();
+        self.deposit_auto_renew_grace_period(deposit_id).clear();
+        self.deposit_lock_tier(deposit_id).clear();
+        self.record_pong(&deposit.token_id, &deposit.amount);
+
+        let refund = &deposit.amount - penalty;
+        self.send().direct(
+            receiver,
+            &deposit.token_id,
+            token_nonce,
+            &(&refund + reward),
+        );
+        self.pong_event(&owner, receiver, deposit_id, &deposit.token_id, &refund);
+        if *reward > 0 {
+            self.reward_event(&owner, deposit_id, &deposit.token_id, reward);
+        }
+    }
+
+    /// The default tier follows the current `duration_in_seconds`.
+    fn require_lock_tier(&self, tier_id: u64) -> LockTier {
+        if tier_id == DEFAULT_LOCK_TIER {
+            return self.get_default_lock_tier();
+        }
+
+        let lock_tier = self.lock_tier(tier_id);
+        require!(!lock_tier.is_empty(), "Lock tier not found");
+
+        lock_tier.get()
+    }
+
+    fn get_default_lock_tier(&self) -> LockTier {
+        LockTier {
+            duration_in_seconds: self.duration_in_seconds().get(),
+            reward_multiplier: BASE_REWARD_MULTIPLIER,
+        }
+    }
+
+    /// A deposit can be `pong`-ed once its lock period is over, or at any time in emergency mode.
+    fn is_unlocked(&self, deposit: &DepositInfo<Self::Api>, current_timestamp: u64) -> bool {
+        self.emergency().get() || current_timestamp >= deposit.unlock_timestamp
+    }
+
+    /// The deposit as it stands now, auto-renew included.
+    fn load_deposit(&self, deposit_id: u64) -> DepositInfo<Self::Api> {
+        let mut deposit = self.deposit(deposit_id).get();
+        if self.deposit_auto_renew(deposit_id).get() {
+            deposit.unlock_timestamp = self.get_renewed_unlock_timestamp(
+                deposit.unlock_timestamp,
+                self.deposit_lock_tier(deposit_id).get().duration_in_seconds,
+                self.deposit_auto_renew_grace_period(deposit_id).get(),
+                self.blockchain().get_block_timestamp(),
+            );
+        }
+
+        deposit
+    }
+
+    /// Stores an open position that changed. Its receipt, if it has one, is replaced by one describing it, sent to `holder`.
+    fn update_deposit(
+        &self,
+        deposit_id: u64,
+        deposit: DepositInfo<Self::Api>,
+        holder: &ManagedAddress,
+    ) {
+        if self.burn_receipt(deposit_id) {
+            self.mint_receipt(deposit_id, &deposit, holder);
+        }
+        self.deposit(deposit_id).set(deposit);
+    }
+
+    /// Mints the receipt of a new deposit, if the receipt collection was issued, for whoever can `pong` it.
+    fn send_receipt(&self, deposit_id: u64) {
+        let deposit = self.load_deposit(deposit_id);
+        self.mint_receipt(deposit_id, &deposit, &self.get_ponger(deposit_id));
+    }
+
+    /// A receipt sent along by someone else than whoever can `pong` its deposit has changed hands:
+    /// the deposit is moved to the caller, as if they had made it.
+    fn take_over_receipt_deposit(&self, caller: &ManagedAddress, deposit_id: u64) {
+        if *caller == self.get_ponger(deposit_id) {
+            return;
+        }
+
+        self.remove_user_deposit(&self.deposit_owner(deposit_id).get(), deposit_id);
+        self.deposit_owner(deposit_id).set(caller);
+        self.deposit_funder(deposit_id).clear();
+        self.deposit_ping_for_ponger(deposit_id).clear();
+        self.user_deposit_ids(caller).insert(deposit_id);
+        self.active_users().insert(caller.clone());
+    }
+
+    /// Moves the unlock time of an already unlocked deposit up to now.
+    /// Its ping time moves along, so that the reward does not accrue over the time the deposit sat unlocked.
+    fn restart_unlocked_deposit(&self, deposit: &mut DepositInfo<Self::Api>) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        if deposit.unlock_timestamp < current_timestamp {
+            deposit.ping_timestamp += current_timestamp - deposit.unlock_timestamp;
+            deposit.unlock_timestamp = current_timestamp;
+        }
+    }
+
+    /// Whether the user holds a deposit they paid for themselves. Deposits made for them with `pingFor` are left out.
+    fn has_own_deposit(&self, address: &ManagedAddress) -> bool {
+        self.user_deposit_ids(address)
+            .iter()
+            .any(|deposit_id| self.deposit_funder(deposit_id).is_empty())
+    }
+
+    fn remove_user_deposit(&self, address: &ManagedAddress, deposit_id: u64) {
+        let mut user_deposit_ids = self.user_deposit_ids(address);
+        user_deposit_ids.swap_remove(&deposit_id);
+        if user_deposit_ids.is_empty() {
+            self.active_users().swap_remove(address);
+        }
+    }
+
+    /// The address allowed to `pong` a deposit: its owner, or its funder if the owner configured so when it was made.
+    fn get_ponger(&self, deposit_id: u64) -> ManagedAddress {
+        let funder = self.deposit_funder(deposit_id);
+        if !funder.is_empty()
+            && self.deposit_ping_for_ponger(deposit_id).get() == PingForPonger::Funder
+        {
+            return funder.get();
+        }
+
+        self.deposit_owner(deposit_id).get()
+    }
+
+    /// The user's deposit that unlocks first, ties going to the older one.
+    fn get_next_deposit(&self, address: &ManagedAddress) -> Option<(u64, DepositInfo<Self::Api>)> {
+        self.migrate_legacy_deposit(address);
+        self.user_deposit_ids(address)
+            .iter()
+            .map(|deposit_id| (deposit_id, self.load_deposit(deposit_id)))
+            .min_by_key(|(deposit_id, deposit)| (deposit.unlock_timestamp, *deposit_id))
+    }
+
+    fn set_activation_window(&self, ping_start_timestamp: u64, ping_end_timestamp: u64) {
+        require!(
+            ping_end_timestamp == 0 || ping_start_timestamp < ping_end_timestamp,
+            "Ping start must be before ping end"
+        );
+
+        self.ping_start_timestamp().set(ping_start_timestamp);
+        self.ping_end_timestamp().set(ping_end_timestamp);
+        self.activation_window_event(ping_start_timestamp, ping_end_timestamp);
+    }
+
+    fn require_ping_window_open(&self) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp >= self.ping_start_timestamp().get(),
+            "Ping is not open yet"
+        );
+
+        let ping_end_timestamp = self.ping_end_timestamp().get();
+        require!(
+            ping_end_timestamp == 0 || current_timestamp < ping_end_timestamp,
+            "Ping is closed"
+        );
     }
 
     // views
 
     #[view(didUserPing)]
     fn did_user_ping(&self, address: &ManagedAddress) -> bool {
-        !self.user_ping_timestamp(address).is_empty()
+        !self.user_deposit_ids(address).is_empty()
+            || !self.legacy_user_ping_timestamp(address).is_empty()
     }
 
-    #[view(getPongEnableTimestamp)]
-    fn get_pong_enable_timestamp(&self, address: &ManagedAddress) -> u64 {
-        if !self.did_user_ping(address) {
-            return 0;
+    /// When the user holds several deposits, this and the other per-user views describe the one that unlocks first.
+    #[view(getUserDeposit)]
+    fn get_user_deposit(&self, address: &ManagedAddress) -> OptionalValue<DepositInfo<Self::Api>> {
+        self.get_next_deposit(address)
+            .map(|(_, deposit)| deposit)
+            .into()
+    }
+
+    /// Lists all deposits of the user, as (deposit id, deposit).
+    #[view(getUserDeposits)]
+    fn get_user_deposits(
+        &self,
+        address: &ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u64, DepositInfo<Self::Api>>> {
+        self.migrate_legacy_deposit(address);
+        let mut result = MultiValueEncoded::new();
+        for deposit_id in self.user_deposit_ids(address).iter() {
+            result.push((deposit_id, self.load_deposit(deposit_id)).into());
+        }
+
+        result
+    }
+
+    #[view(getDeposit)]
+    fn get_deposit(&self, deposit_id: u64) -> OptionalValue<DepositInfo<Self::Api>> {
+        if self.deposit(deposit_id).is_empty() {
+            return OptionalValue::None;
         }
 
-        let user_ping_timestamp = self.user_ping_timestamp(address).get();
-        let duration_in_seconds = self.duration_in_seconds().get();
+        OptionalValue::Some(self.load_deposit(deposit_id))
+    }
+
+    #[view(getUserPingTimestamp)]
+    fn get_user_ping_timestamp(&self, address: &ManagedAddress) -> u64 {
+        match self.get_next_deposit(address) {
+            Some((_, deposit)) => deposit.ping_timestamp,
+            None => 0,
+        }
+    }
 
-        user_ping_timestamp + duration_in_seconds
+    /// The timestamp from which the user can `pong`, as fixed when they `ping`-ed.
+    /// Later changes to `duration_in_seconds` do not move it.
+    #[view(getPongEnableTimestamp)]
+    fn get_pong_enable_timestamp(&self, address: &ManagedAddress) -> u64 {
+        match self.get_next_deposit(address) {
+            Some((_, deposit)) => deposit.unlock_timestamp,
+            None => 0,
+        }
     }
 
     #[view(getTimeToPong)]
@@ -130,26 +941,167 @@ pub trait PingPong {
         }
     }
 
-    // storage
+    /// The penalty the user would pay to `earlyPong` their next deposit to unlock right now.
+    #[view(getEarlyPongPenalty)]
+    fn get_early_pong_penalty(&self, address: &ManagedAddress) -> BigUint {
+        match self.get_next_deposit(address) {
+            Some((deposit_id, _)) => self.get_deposit_early_pong_penalty(deposit_id),
+            None => BigUint::zero(),
+        }
+    }
 
-    #[view(getAcceptedPaymentToken)]
-    #[storage_mapper("acceptedPaymentTokenId")]
-    fn accepted_payment_token_id(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+    #[view(getDepositEarlyPongPenalty)]
+    fn get_deposit_early_pong_penalty(&self, deposit_id: u64) -> BigUint {
+        if self.deposit(deposit_id).is_empty() {
+            return BigUint::zero();
+        }
 
-    #[view(getPingAmount)]
-    #[storage_mapper("pingAmount")]
-    fn ping_amount(&self) -> SingleValueMapper<BigUint>;
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        self.compute_early_pong_penalty(&self.load_deposit(deposit_id), current_timestamp)
+    }
+
+    /// The reward accrued so far by the user's next deposit to unlock, paid on `pong` if the pool allows it.
+    #[view(getPendingReward)]
+    fn get_pending_reward(&self, address: &ManagedAddress) -> BigUint {
+        match self.get_next_deposit(address) {
+            Some((deposit_id, deposit)) => {
+                let current_timestamp = self.blockchain().get_block_timestamp();
+                self.compute_reward(deposit_id, &deposit, current_timestamp)
+            }
+            None => BigUint::zero(),
+        }
+    }
+
+    #[view(getDepositPendingReward)]
+    fn get_deposit_pending_reward(&self, deposit_id: u64) -> BigUint {
+        if self.deposit(deposit_id).is_empty() {
+            return BigUint::zero();
+        }
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        self.compute_reward(
+            deposit_id,
+            &self.load_deposit(deposit_id),
+            current_timestamp,
+        )
+    }
+
+    /// Lists the lock tiers that can be picked on `ping`, as (tier id, duration in seconds, reward multiplier), the default tier first.
+    #[view(getLockTiers)]
+    fn get_lock_tiers(&self) -> MultiValueEncoded<MultiValue3<u64, u64, u64>> {
+        let mut result = MultiValueEncoded::new();
+        let default_lock_tier = self.get_default_lock_tier();
+        result.push(
+            (
+                DEFAULT_LOCK_TIER,
+                default_lock_tier.duration_in_seconds,
+                default_lock_tier.reward_multiplier,
+            )
+                .into(),
+        );
+        for tier_id in self.lock_tier_ids().iter() {
+            let lock_tier = self.lock_tier(tier_id).get();
+            result.push(
+                (
+                    tier_id,
+                    lock_tier.duration_in_seconds,
+                    lock_tier.reward_multiplier,
+                )
+                    .into(),
+            );
+        }
+
+        result
+    }
+
+    #[view(getDepositLockTier)]
+    fn get_deposit_lock_tier(&self, deposit_id: u64) -> OptionalValue<LockTier> {
+        if self.deposit(deposit_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.deposit_lock_tier(deposit_id).get())
+    }
+
+    /// The start and end timestamps between which `ping` is accepted, 0 meaning no bound.
+    /// `pong` is never restricted by this window.
+    #[view(getActivationWindow)]
+    fn get_activation_window(&self) -> MultiValue2<u64, u64> {
+        (
+            self.ping_start_timestamp().get(),
+            self.ping_end_timestamp().get(),
+        )
+            .into()
+    }
+
+    #[view(getActiveUserCount)]
+    fn get_active_user_count(&self) -> usize {
+        self.active_users().len()
+    }
+
+    /// Lists the users that currently have funds locked, as (address, amount, unlock timestamp) of their next deposit to unlock.
+    /// `from` is a zero-based offset, `size` is the maximum number of entries returned.
+    /// The order is not stable across `pong` calls, since removals swap the last user into the freed slot.
+    /// A user listed without any deposit is skipped.
+    #[view(getActiveUsers)]
+    fn get_active_users(
+        &self,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<MultiValue3<ManagedAddress, BigUint, u64>> {
+        let active_users = self.active_users();
+        let end = core::cmp::min(from.saturating_add(size), active_users.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in from..end {
+            let address = active_users.get_by_index(index + 1);
+            if let Some((_, deposit)) = self.get_next_deposit(&address) {
+                result.push((address, deposit.amount, deposit.unlock_timestamp).into());
+            }
+        }
+
+        result
+    }
+
+    // storage
 
     #[view(getDurationTimestamp)]
     #[storage_mapper("durationInSeconds")]
     fn duration_in_seconds(&self) -> SingleValueMapper<u64>;
 
-    #[view(getUserPingTimestamp)]
-    #[storage_mapper("userPingTimestamp")]
-    fn user_ping_timestamp(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+    #[storage_mapper("pingStartTimestamp")]
+    fn ping_start_timestamp(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("pingEndTimestamp")]
+    fn ping_end_timestamp(&self) -> SingleValueMapper<u64>;
+
+    #[view(isMultipleDepositsEnabled)]
+    #[storage_mapper("multipleDepositsEnabled")]
+    fn multiple_deposits_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("lastDepositId")]
+    fn last_deposit_id(&self) -> SingleValueMapper<u64>;
+
+    /// The last deposit looked at by `pongMatured`, the next call resuming after it.
+    #[view(getPongMaturedCursor)]
+    #[storage_mapper("pongMaturedCursor")]
+    fn pong_matured_cursor(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("deposit")]
+    fn deposit(&self, deposit_id: u64) -> SingleValueMapper<DepositInfo<Self::Api>>;
+
+    /// The nonce of the NFT, SFT or meta-ESDT locked in a deposit, 0 for fungible tokens.
+    #[view(getDepositTokenNonce)]
+    #[storage_mapper("depositTokenNonce")]
+    fn deposit_token_nonce(&self, deposit_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getDepositOwner)]
+    #[storage_mapper("depositOwner")]
+    fn deposit_owner(&self, deposit_id: u64) -> SingleValueMapper<ManagedAddress>;
 
-    // events
+    #[storage_mapper("userDepositIds")]
+    fn user_deposit_ids(&self, address: &ManagedAddress) -> UnorderedSetMapper<u64>;
 
-    #[event("pongEvent")]
-    fn pong_event(&self, #[indexed] user: &ManagedAddress);
+    #[storage_mapper("activeUsers")]
+    fn active_users(&self) -> UnorderedSetMapper<ManagedAddress>;
 }
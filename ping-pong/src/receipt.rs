@@ -0,0 +1,212 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::deposit_info::DepositInfo;
+
+/// Restricts transfers of a token to those going to or from the holders of the role.
+const ESDT_TRANSFER_ROLE: &str = "ESDTTransferRole";
+
+/// What a receipt says about the deposit it was minted for.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ReceiptAttributes<M: ManagedTypeApi> {
+    pub deposit_id: u64,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub unlock_timestamp: u64,
+}
+
+/// Makes positions visible in wallets: once the owner issues the receipt collection and sets up its transfers,
+/// every `ping` mints an NFT describing the deposit. Whoever holds the receipt can `pong` the deposit by sending it back, and it is burned.
+/// Receipts can only be sent back to the contract, unless the owner makes them tradable.
+/// Deposits made before the receipts were set up have no receipt and are `pong`-ed as before.
+#[multiversx_sc::module]
+pub trait ReceiptModule {
+    /// Issues the receipt NFT collection, the EGLD sent paying the issue cost.
+    /// No receipt is minted until `setReceiptTransferable` is called: `false` gives the contract the ESDT transfer role,
+    /// which restricts receipts to transfers back to the contract, `true` lets them be traded like any NFT.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueReceiptToken)]
+    fn issue_receipt_token(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        require!(
+            self.receipt_token().is_empty(),
+            "Receipt token already issued"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let issue_cost = self.call_value().egld().clone();
+        self.receipt_token().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            Some(self.callbacks().receipt_token_issue_callback(&caller)),
+        );
+    }
+
+    /// Restricts transfers of the receipts, through the ESDT transfer role, so that they can only be sent back to the contract, or lifts the restriction.
+    #[only_owner]
+    #[endpoint(setReceiptTransferable)]
+    fn set_receipt_transferable(&self, transferable: bool) {
+        self.receipt_token().require_issued_or_set();
+
+        // the role was never set, so there is nothing to unset
+        if transferable && !self.receipt_transfer_role_set().get() {
+            self.receipt_transferable().set(true);
+            self.receipt_transferable_event(true);
+            return;
+        }
+
+        let sc_address = self.blockchain().get_sc_address();
+        let token_id = self.receipt_token().get_token_id();
+        let function_name = if transferable {
+            "unSetSpecialRole"
+        } else {
+            "setSpecialRole"
+        };
+
+        self.tx()
+            .to(ESDTSystemSCAddress)
+            .raw_call(function_name)
+            .argument(&token_id)
+            .argument(&sc_address)
+            .argument(&ESDT_TRANSFER_ROLE)
+            .callback(
+                self.callbacks()
+                    .receipt_transfer_role_callback(transferable),
+            )
+            .async_call_and_exit();
+    }
+
+    /// Mints a receipt for the deposit and sends it to `to`, if the receipt collection was issued and its transfers set up.
+    fn mint_receipt(&self, deposit_id: u64, deposit: &DepositInfo<Self::Api>, to: &ManagedAddress) {
+        if !self.receipt_token().get_token_state().is_set()
+            || !(self.receipt_transfer_role_set().get() || self.receipt_transferable().get())
+        {
+            return;
+        }
+
+        let attributes = ReceiptAttributes {
+            deposit_id,
+            token_id: deposit.token_id.clone(),
+            amount: deposit.amount.clone(),
+            unlock_timestamp: deposit.unlock_timestamp,
+        };
+        let receipt =
+            self.receipt_token()
+                .nft_create_and_send(to, BigUint::from(1u32), &attributes);
+        self.deposit_receipt_nonce(deposit_id)
+            .set(receipt.token_nonce);
+        self.receipt_deposit_id(receipt.token_nonce).set(deposit_id);
+    }
+
+    /// The nonces of the receipts sent with the call. Anything else than receipts is rejected.
+    fn get_paid_receipt_nonces(&self) -> ManagedVec<u64> {
+        let receipt_token = self.receipt_token();
+        let mut receipt_nonces = ManagedVec::new();
+        for payment in self.call_value().all_transfers().iter() {
+            require!(
+                receipt_token.get_token_state().is_set()
+                    && payment.token_identifier
+                        == EgldOrEsdtTokenIdentifier::esdt(receipt_token.get_token_id()),
+                "Invalid receipt"
+            );
+            receipt_nonces.push(payment.token_nonce);
+        }
+
+        receipt_nonces
+    }
+
+    /// The deposit a receipt was minted for, as long as it was not `pong`-ed.
+    fn require_receipt_deposit(&self, receipt_nonce: u64) -> u64 {
+        let deposit_id = self.receipt_deposit_id(receipt_nonce).get();
+        require!(deposit_id != 0, "Invalid receipt");
+
+        deposit_id
+    }
+
+    /// Burns the receipt of the deposit, if it has one. It must have been sent along.
+    /// Returns whether the deposit had a receipt.
+    fn burn_receipt(&self, deposit_id: u64) -> bool {
+        let receipt_nonce = self.deposit_receipt_nonce(deposit_id).take();
+        if receipt_nonce == 0 {
+            return false;
+        }
+
+        self.receipt_deposit_id(receipt_nonce).clear();
+        self.receipt_token()
+            .nft_burn(receipt_nonce, &BigUint::from(1u32));
+        true
+    }
+
+    // callbacks
+
+    #[callback]
+    fn receipt_token_issue_callback(
+        &self,
+        caller: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.receipt_token().set_token_id(token_id.clone());
+                self.receipt_token_event(&token_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.receipt_token().clear();
+
+                let returned_cost = self.call_value().egld().clone();
+                if returned_cost > 0 {
+                    self.tx().to(caller).egld(returned_cost).transfer();
+                }
+            }
+        }
+    }
+
+    #[callback]
+    fn receipt_transfer_role_callback(
+        &self,
+        transferable: bool,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(()) = result {
+            self.receipt_transfer_role_set().set(!transferable);
+            self.receipt_transferable().set(transferable);
+            self.receipt_transferable_event(transferable);
+        }
+    }
+
+    // storage
+
+    #[view(getReceiptTokenId)]
+    #[storage_mapper("receiptTokenId")]
+    fn receipt_token(&self) -> NonFungibleTokenMapper;
+
+    #[view(isReceiptTransferable)]
+    #[storage_mapper("receiptTransferable")]
+    fn receipt_transferable(&self) -> SingleValueMapper<bool>;
+
+    /// Whether the contract holds the ESDT transfer role of the receipts.
+    #[storage_mapper("receiptTransferRoleSet")]
+    fn receipt_transfer_role_set(&self) -> SingleValueMapper<bool>;
+
+    /// The nonce of the receipt minted for a deposit, 0 if it has none.
+    #[view(getDepositReceiptNonce)]
+    #[storage_mapper("depositReceiptNonce")]
+    fn deposit_receipt_nonce(&self, deposit_id: u64) -> SingleValueMapper<u64>;
+
+    /// The deposit a receipt was minted for, 0 once it was `pong`-ed.
+    #[view(getReceiptDepositId)]
+    #[storage_mapper("receiptDepositId")]
+    fn receipt_deposit_id(&self, receipt_nonce: u64) -> SingleValueMapper<u64>;
+
+    // events
+
+    #[event("receiptTokenEvent")]
+    fn receipt_token_event(&self, #[indexed] token_id: &TokenIdentifier);
+
+    #[event("receiptTransferableEvent")]
+    fn receipt_transferable_event(&self, #[indexed] transferable: bool);
+}
@@ -0,0 +1,161 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::deposit_info::DepositInfo;
+use crate::lock_tiers::BASE_REWARD_MULTIPLIER;
+
+pub const MAX_REWARD_RATE: u64 = 10_000;
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// What `pong` does when the pool cannot cover the whole reward.
+/// `Fail` - `pong` fails until the owner funds the pool again.
+/// `PrincipalOnly` - `pong` refunds the principal and no reward.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EmptyPoolPolicy {
+    Fail,
+    PrincipalOnly,
+}
+
+/// An owner-funded pool paying yield on `pong`, in the token of each deposit.
+/// The reward rate is yearly, in basis points, and is fixed for each deposit when it is `ping`-ed.
+/// Rewards accrue from `ping` until the deposit unlocks, and are forfeited by `earlyPong`.
+/// In emergency mode, they stop accruing at the time of the `pong`, and are paid only if the pool can cover them.
+#[multiversx_sc::module]
+pub trait RewardsModule: crate::emergency::EmergencyModule + crate::roles::RolesModule {
+    #[only_owner]
+    #[payable]
+    #[endpoint(fundRewards)]
+    fn fund_rewards(&self) {
+        let (token_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(amount > 0, "Must send some rewards");
+
+        self.reward_pool(&token_id).update(|pool| *pool += &amount);
+        self.fund_rewards_event(&token_id, &amount);
+    }
+
+    /// Only applies to deposits made from now on.
+    #[endpoint(setRewardConfig)]
+    fn set_reward_config(&self, reward_rate: u64, empty_pool_policy: EmptyPoolPolicy) {
+        self.require_config_manager();
+
+        require!(
+            reward_rate <= MAX_REWARD_RATE,
+            "Reward rate cannot exceed 100%"
+        );
+
+        self.reward_rate().set(reward_rate);
+        self.empty_pool_policy().set(empty_pool_policy);
+        self.reward_config_event(reward_rate, empty_pool_policy);
+    }
+
+    /// Fixes the current reward rate, scaled by the multiplier of its lock tier, for a deposit that starts a lock period.
+    /// The scaled rate never exceeds 100%.
+    fn snapshot_reward_rate(&self, deposit_id: u64, reward_multiplier: u64) {
+        let reward_rate = core::cmp::min(
+            self.reward_rate().get() * reward_multiplier / BASE_REWARD_MULTIPLIER,
+            MAX_REWARD_RATE,
+        );
+        if reward_rate > 0 {
+            self.deposit_reward_rate(deposit_id).set(reward_rate);
+        } else {
+            self.deposit_reward_rate(deposit_id).clear();
+        }
+    }
+
+    fn compute_reward(
+        &self,
+        deposit_id: u64,
+        deposit: &DepositInfo<Self::Api>,
+        current_timestamp: u64,
+    ) -> BigUint {
+        let reward_rate = self.deposit_reward_rate(deposit_id).get();
+        let accrual_end = core::cmp::min(current_timestamp, deposit.unlock_timestamp);
+        let time_locked = accrual_end.saturating_sub(deposit.ping_timestamp);
+
+        &deposit.amount * reward_rate * time_locked / (MAX_REWARD_RATE * SECONDS_PER_YEAR)
+    }
+
+    /// Takes the reward of an unlocked deposit out of the pool, as allowed by the empty pool policy.
+    /// In emergency mode, a pool short of the reward never fails the refund of the principal.
+    fn take_reward(&self, deposit_id: u64, deposit: &DepositInfo<Self::Api>) -> BigUint {
+        match self.try_take_reward(deposit_id, deposit) {
+            Some(reward) => reward,
+            None => sc_panic!("Not enough rewards in the pool"),
+        }
+    }
+
+    /// Like `take_reward`, but returns `None` instead of failing when the empty pool policy does not allow a refund without the reward.
+    fn try_take_reward(
+        &self,
+        deposit_id: u64,
+        deposit: &DepositInfo<Self::Api>,
+    ) -> Option<BigUint> {
+        let emergency = self.emergency().get();
+        let accrual_end = if emergency {
+            self.blockchain().get_block_timestamp()
+        } else {
+            deposit.unlock_timestamp
+        };
+        let reward = self.compute_reward(deposit_id, deposit, accrual_end);
+        if reward == 0 {
+            return Some(reward);
+        }
+
+        let pool = self.reward_pool(&deposit.token_id);
+        if pool.get() < reward {
+            if emergency || self.empty_pool_policy().get() == EmptyPoolPolicy::PrincipalOnly {
+                return Some(BigUint::zero());
+            }
+            return None;
+        }
+
+        pool.update(|pool| *pool -= &reward);
+        Some(reward)
+    }
+
+    #[view(getRewardConfig)]
+    fn get_reward_config(&self) -> MultiValue2<u64, EmptyPoolPolicy> {
+        (self.reward_rate().get(), self.empty_pool_policy().get()).into()
+    }
+
+    // storage
+
+    #[storage_mapper("rewardRate")]
+    fn reward_rate(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("emptyPoolPolicy")]
+    fn empty_pool_policy(&self) -> SingleValueMapper<EmptyPoolPolicy>;
+
+    #[view(getRewardPool)]
+    #[storage_mapper("rewardPool")]
+    fn reward_pool(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("depositRewardRate")]
+    fn deposit_reward_rate(&self, deposit_id: u64) -> SingleValueMapper<u64>;
+
+    // events
+
+    #[event("fundRewardsEvent")]
+    fn fund_rewards_event(
+        &self,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("rewardConfigEvent")]
+    fn reward_config_event(
+        &self,
+        #[indexed] reward_rate: u64,
+        #[indexed] empty_pool_policy: EmptyPoolPolicy,
+    );
+
+    #[event("rewardEvent")]
+    fn reward_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] deposit_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] reward: &BigUint,
+    );
+}
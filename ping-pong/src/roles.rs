@@ -0,0 +1,81 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// `Pauser` - can pause and unpause `ping` and `pong`.
+/// `ConfigManager` - can change the amounts, durations and tokens accepted, and how deposits are handled.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Pauser,
+    ConfigManager,
+}
+
+/// Splits the admin power, so that the owner does not have to hand out its own key for day to day operations.
+/// Only the owner grants and revokes roles, and it can still do everything a role allows.
+#[multiversx_sc::module]
+pub trait RolesModule {
+    #[only_owner]
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        require!(
+            self.role_members(role).insert(address.clone()),
+            "Address already has the role"
+        );
+
+        self.role_granted_event(role, &address);
+    }
+
+    #[only_owner]
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        require!(
+            self.role_members(role).swap_remove(&address),
+            "Address does not have the role"
+        );
+
+        self.role_revoked_event(role, &address);
+    }
+
+    fn require_pauser(&self) {
+        require!(
+            self.is_owner_or_role_member(Role::Pauser),
+            "Endpoint can only be called by owner or pauser"
+        );
+    }
+
+    fn require_config_manager(&self) {
+        require!(
+            self.is_owner_or_role_member(Role::ConfigManager),
+            "Endpoint can only be called by owner or config manager"
+        );
+    }
+
+    fn is_owner_or_role_member(&self, role: Role) -> bool {
+        let caller = self.blockchain().get_caller();
+        caller == self.blockchain().get_owner_address() || self.role_members(role).contains(&caller)
+    }
+
+    /// The owner is not listed, even though it can do everything a role allows.
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: ManagedAddress) -> bool {
+        self.role_members(role).contains(&address)
+    }
+
+    #[view(getRoleMembers)]
+    fn get_role_members(&self, role: Role) -> MultiValueEncoded<ManagedAddress> {
+        self.role_members(role).iter().collect()
+    }
+
+    // storage
+
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    // events
+
+    #[event("roleGrantedEvent")]
+    fn role_granted_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("roleRevokedEvent")]
+    fn role_revoked_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+}
@@ -0,0 +1,85 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug,
+)]
+pub struct TokenStats<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub total_locked: BigUint<M>,
+    pub total_pinged: BigUint<M>,
+    pub total_ponged: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Stats<M: ManagedTypeApi> {
+    pub ping_count: u64,
+    pub pong_count: u64,
+    pub tokens: ManagedVec<M, TokenStats<M>>,
+}
+
+/// Running counters, kept up to date on every `ping` and `pong`.
+/// Solvency can be checked by comparing the contract balance of each token against its `total_locked`.
+#[multiversx_sc::module]
+pub trait StatsModule {
+    fn record_ping(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.ping_count().update(|count| *count += 1);
+        self.stats_tokens().insert(token_id.clone());
+        self.total_locked(token_id).update(|total| *total += amount);
+        self.total_pinged(token_id).update(|total| *total += amount);
+    }
+
+    fn record_pong(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.pong_count().update(|count| *count += 1);
+        self.total_locked(token_id).update(|total| *total -= amount);
+        self.total_ponged(token_id).update(|total| *total += amount);
+    }
+
+    /// Counts funds locked before the stats existed, whose `ping`s were never recorded.
+    fn record_legacy_locked(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.stats_tokens().insert(token_id.clone());
+        self.total_locked(token_id).update(|total| *total += amount);
+        self.total_pinged(token_id).update(|total| *total += amount);
+    }
+
+    #[view(getStats)]
+    fn get_stats(&self) -> Stats<Self::Api> {
+        let mut tokens = ManagedVec::new();
+        for token_id in self.stats_tokens().iter() {
+            tokens.push(TokenStats {
+                total_locked: self.total_locked(&token_id).get(),
+                total_pinged: self.total_pinged(&token_id).get(),
+                total_ponged: self.total_ponged(&token_id).get(),
+                token_id,
+            });
+        }
+
+        Stats {
+            ping_count: self.ping_count().get(),
+            pong_count: self.pong_count().get(),
+            tokens,
+        }
+    }
+
+    // storage
+
+    #[storage_mapper("pingCount")]
+    fn ping_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("pongCount")]
+    fn pong_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("statsTokens")]
+    fn stats_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("totalLocked")]
+    fn total_locked(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalPinged")]
+    fn total_pinged(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalPonged")]
+    fn total_ponged(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+}
@@ -10,6 +10,26 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+#[test]
+fn ping_pong_accepted_tokens_rs() {
+    world().run("scenarios/ping-pong-accepted-tokens.scen.json");
+}
+
+#[test]
+fn ping_pong_activation_window_rs() {
+    world().run("scenarios/ping-pong-activation-window.scen.json");
+}
+
+#[test]
+fn ping_pong_active_users_rs() {
+    world().run("scenarios/ping-pong-active-users.scen.json");
+}
+
+#[test]
+fn ping_pong_auto_renew_rs() {
+    world().run("scenarios/ping-pong-auto-renew.scen.json");
+}
+
 #[test]
 fn ping_pong_call_ping_rs() {
     world().run("scenarios/ping-pong-call-ping.scen.json");
@@ -31,8 +51,8 @@ fn ping_pong_call_ping_wrong_amount_rs() {
 }
 
 #[test]
-fn ping_pong_call_pong_rs() {
-    world().run("scenarios/ping-pong-call-pong.scen.json");
+fn ping_pong_call_pong_after_upgrade_rs() {
+    world().run("scenarios/ping-pong-call-pong-after-upgrade.scen.json");
 }
 
 #[test]
@@ -40,6 +60,11 @@ fn ping_pong_call_pong_before_deadline_rs() {
     world().run("scenarios/ping-pong-call-pong-before-deadline.scen.json");
 }
 
+#[test]
+fn ping_pong_call_pong_rs() {
+    world().run("scenarios/ping-pong-call-pong.scen.json");
+}
+
 #[test]
 fn ping_pong_call_pong_twice_rs() {
     world().run("scenarios/ping-pong-call-pong-twice.scen.json");
@@ -50,7 +75,162 @@ fn ping_pong_call_pong_without_ping_rs() {
     world().run("scenarios/ping-pong-call-pong-without-ping.scen.json");
 }
 
+#[test]
+fn ping_pong_config_proposals_rs() {
+    world().run("scenarios/ping-pong-config-proposals.scen.json");
+}
+
+#[test]
+fn ping_pong_early_pong_disabled_rs() {
+    world().run("scenarios/ping-pong-early-pong-disabled.scen.json");
+}
+
+#[test]
+fn ping_pong_early_pong_rs() {
+    world().run("scenarios/ping-pong-early-pong.scen.json");
+}
+
+#[test]
+fn ping_pong_emergency_rewards_rs() {
+    world().run("scenarios/ping-pong-emergency-rewards.scen.json");
+}
+
+#[test]
+fn ping_pong_emergency_rs() {
+    world().run("scenarios/ping-pong-emergency.scen.json");
+}
+
 #[test]
 fn ping_pong_init_rs() {
     world().run("scenarios/ping-pong-init.scen.json");
 }
+
+#[test]
+fn ping_pong_legacy_deposits_rs() {
+    world().run("scenarios/ping-pong-legacy-deposits.scen.json");
+}
+
+#[test]
+fn ping_pong_lock_tiers_rs() {
+    world().run("scenarios/ping-pong-lock-tiers.scen.json");
+}
+
+#[test]
+fn ping_pong_multiple_deposits_rs() {
+    world().run("scenarios/ping-pong-multiple-deposits.scen.json");
+}
+
+#[test]
+fn ping_pong_nft_rs() {
+    world().run("scenarios/ping-pong-nft.scen.json");
+}
+
+#[test]
+fn ping_pong_pause_pong_rs() {
+    world().run("scenarios/ping-pong-pause-pong.scen.json");
+}
+
+#[test]
+fn ping_pong_pause_rs() {
+    world().run("scenarios/ping-pong-pause.scen.json");
+}
+
+#[test]
+fn ping_pong_ping_fee_rs() {
+    world().run("scenarios/ping-pong-ping-fee.scen.json");
+}
+
+#[test]
+fn ping_pong_ping_for_funder_pongs_rs() {
+    world().run("scenarios/ping-pong-ping-for-funder-pongs.scen.json");
+}
+
+#[test]
+fn ping_pong_ping_for_rs() {
+    world().run("scenarios/ping-pong-ping-for.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_matured_rs() {
+    world().run("scenarios/ping-pong-pong-matured.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_matured_short_reward_pool_rs() {
+    world().run("scenarios/ping-pong-pong-matured-short-reward-pool.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_matured_skips_contracts_rs() {
+    world().run("scenarios/ping-pong-pong-matured-skips-contracts.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_partial_rs() {
+    world().run("scenarios/ping-pong-pong-partial.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_to_rs() {
+    world().run("scenarios/ping-pong-pong-to.scen.json");
+}
+
+#[test]
+fn ping_pong_range_mode_rs() {
+    world().run("scenarios/ping-pong-range-mode.scen.json");
+}
+
+#[test]
+fn ping_pong_receipt_holder_pings_twice_rs() {
+    world().run("scenarios/ping-pong-receipt-holder-pings-twice.scen.json");
+}
+
+#[test]
+fn ping_pong_receipt_holder_pongs_rs() {
+    world().run("scenarios/ping-pong-receipt-holder-pongs.scen.json");
+}
+
+#[test]
+fn ping_pong_receipt_not_set_up_rs() {
+    world().run("scenarios/ping-pong-receipt-not-set-up.scen.json");
+}
+
+#[test]
+fn ping_pong_receipt_rs() {
+    world().run("scenarios/ping-pong-receipt.scen.json");
+}
+
+#[test]
+fn ping_pong_receipt_seller_pings_again_rs() {
+    world().run("scenarios/ping-pong-receipt-seller-pings-again.scen.json");
+}
+
+#[test]
+fn ping_pong_rewards_auto_renew_rs() {
+    world().run("scenarios/ping-pong-rewards-auto-renew.scen.json");
+}
+
+#[test]
+fn ping_pong_rewards_extend_lock_rs() {
+    world().run("scenarios/ping-pong-rewards-extend-lock.scen.json");
+}
+
+#[test]
+fn ping_pong_rewards_rs() {
+    world().run("scenarios/ping-pong-rewards.scen.json");
+}
+
+#[test]
+fn ping_pong_roles_rs() {
+    world().run("scenarios/ping-pong-roles.scen.json");
+}
+
+#[test]
+fn ping_pong_stats_rs() {
+    world().run("scenarios/ping-pong-stats.scen.json");
+}
+
+#[test]
+fn ping_pong_upgrade_keeps_open_positions_rs() {
+    world().run("scenarios/ping-pong-upgrade-keeps-open-positions.scen.json");
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            9
-// Async Callback (empty):               1
-// Total number of exported functions:  12
+// Endpoints:                           94
+// Async Callback:                       1
+// Total number of exported functions:  97
 
 #![no_std]
 
@@ -20,16 +20,101 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         upgrade => upgrade
+        executeConfigProposal => execute_config_proposal
+        setMultipleDeposits => set_multiple_deposits
         ping => ping
+        pingFor => ping_for
         pong => pong
+        pongTo => pong_to
+        earlyPong => early_pong
+        pongPartial => pong_partial
+        extendLock => extend_lock
+        setAutoRenew => set_auto_renew
+        pongAll => pong_all
+        pongMatured => pong_matured
+        emergencyRefund => emergency_refund
         didUserPing => did_user_ping
+        getUserDeposit => get_user_deposit
+        getUserDeposits => get_user_deposits
+        getDeposit => get_deposit
+        getUserPingTimestamp => get_user_ping_timestamp
         getPongEnableTimestamp => get_pong_enable_timestamp
         getTimeToPong => get_time_to_pong
-        getAcceptedPaymentToken => accepted_payment_token_id
-        getPingAmount => ping_amount
+        getEarlyPongPenalty => get_early_pong_penalty
+        getDepositEarlyPongPenalty => get_deposit_early_pong_penalty
+        getPendingReward => get_pending_reward
+        getDepositPendingReward => get_deposit_pending_reward
+        getLockTiers => get_lock_tiers
+        getDepositLockTier => get_deposit_lock_tier
+        getActivationWindow => get_activation_window
+        getActiveUserCount => get_active_user_count
+        getActiveUsers => get_active_users
         getDurationTimestamp => duration_in_seconds
-        getUserPingTimestamp => user_ping_timestamp
+        isMultipleDepositsEnabled => multiple_deposits_enabled
+        getPongMaturedCursor => pong_matured_cursor
+        getDepositTokenNonce => deposit_token_nonce
+        getDepositOwner => deposit_owner
+        addAcceptedToken => add_accepted_token
+        removeAcceptedToken => remove_accepted_token
+        setAcceptedTokenNonce => set_accepted_token_nonce
+        getPingAmount => get_ping_amount
+        getMaxPingAmount => get_max_ping_amount
+        getAcceptedTokens => get_accepted_tokens
+        getAcceptedPaymentToken => accepted_payment_token_id
+        getTokenPingAmount => token_ping_amount
+        getTokenMaxPingAmount => token_max_ping_amount
+        getAcceptedTokenNonce => token_nonce
+        setAutoRenewGracePeriod => set_auto_renew_grace_period
+        getAutoRenewGracePeriod => auto_renew_grace_period
+        isAutoRenewEnabled => deposit_auto_renew
+        setConfigTimelock => set_config_timelock
+        proposeConfig => propose_config
+        cancelConfigProposal => cancel_config_proposal
+        getConfigTimelock => get_config_timelock
+        getPendingConfigTimelock => get_pending_config_timelock
+        getPendingConfigProposals => get_pending_config_proposals
+        setEarlyPongPenalty => set_early_pong_penalty
+        getEarlyPongPenaltyConfig => get_early_pong_penalty_config
+        getTreasury => get_treasury
+        enterEmergency => enter_emergency
+        getEmergencyTimestamp => get_emergency_timestamp
+        isEmergency => emergency
+        getEmergencyRefundCursor => emergency_refund_cursor
+        setLockTier => set_lock_tier
+        removeLockTier => remove_lock_tier
+        setPartialPongRemainder => set_partial_pong_remainder
+        getPartialPongRemainder => partial_pong_remainder
+        pause => pause
+        unpause => unpause
+        pausePong => pause_pong
+        unpausePong => unpause_pong
+        isPaused => ping_paused
+        isPongPaused => pong_paused
+        setPingFee => set_ping_fee
+        setFeeCollector => set_fee_collector
+        claimFees => claim_fees
+        getPingFeeConfig => get_ping_fee_config
+        getFeeCollector => get_fee_collector
+        getAccumulatedFees => get_accumulated_fees
+        setPingForPonger => set_ping_for_ponger
+        getDepositFunder => get_deposit_funder
+        getPingForPonger => ping_for_ponger
+        issueReceiptToken => issue_receipt_token
+        setReceiptTransferable => set_receipt_transferable
+        getReceiptTokenId => receipt_token
+        isReceiptTransferable => receipt_transferable
+        getDepositReceiptNonce => deposit_receipt_nonce
+        getReceiptDepositId => receipt_deposit_id
+        fundRewards => fund_rewards
+        setRewardConfig => set_reward_config
+        getRewardConfig => get_reward_config
+        getRewardPool => reward_pool
+        grantRole => grant_role
+        revokeRole => revoke_role
+        hasRole => has_role
+        getRoleMembers => get_role_members
+        getStats => get_stats
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { ping_pong }